@@ -0,0 +1,32 @@
+extern crate sigar_rs;
+
+use sigar_rs::fs;
+
+fn main() {
+    println!(
+        "{:<24} {:>12} {:>12} {:>12} {:>5} {:>10} {:>6}  Mounted on",
+        "Filesystem", "Size", "Used", "Avail", "Use%", "IFree", "IUse%"
+    );
+
+    for one in fs::list().unwrap() {
+        let usage = match fs::usage(&one.dir_name) {
+            Ok(usage) => usage,
+            Err(_) => continue,
+        };
+        if usage.total == 0 {
+            continue;
+        }
+
+        println!(
+            "{:<24} {:>12} {:>12} {:>12} {:>4.0}% {:>10} {:>5.0}%  {}",
+            one.dev_name,
+            usage.total,
+            usage.used,
+            usage.avail,
+            usage.use_percent * 100.0,
+            usage.free_files,
+            usage.files_use_percent() * 100.0,
+            one.dir_name
+        );
+    }
+}
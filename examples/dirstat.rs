@@ -0,0 +1,17 @@
+extern crate sigar_rs;
+
+use sigar_rs::fs;
+use std::env;
+
+fn main() {
+    let path = env::args().nth(1).unwrap_or_else(|| ".".to_string());
+
+    let stat = fs::dir_stat(&path).unwrap();
+    println!("{} (this level): {:?}", path, stat);
+
+    let usage = fs::dir_usage(&path).unwrap();
+    println!(
+        "{} (recursive): {} entries, {} files, {} dirs, {} symlinks, {} bytes",
+        path, usage.total, usage.files, usage.subdirs, usage.symlinks, usage.disk_usage
+    );
+}
@@ -0,0 +1,39 @@
+extern crate sigar_rs;
+
+use sigar_rs::fs;
+use std::env;
+
+fn main() {
+    let path = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "/etc/passwd".to_string());
+
+    let all = [
+        ("file", fs::file_attrs(&path)),
+        ("link", fs::link_attrs(&path)),
+    ];
+    for (name, attrs) in all.iter() {
+        match attrs {
+            Ok(attrs) => println!(
+                "{} {}: {} {}{} {:04o} uid={} gid={} size={} inode={} nlink={} mtime={:?}",
+                name,
+                path,
+                attrs.type_,
+                match attrs.type_ {
+                    fs::FileType::Directory => "d",
+                    fs::FileType::Symlink => "l",
+                    _ => "-",
+                },
+                attrs.permissions_string(),
+                attrs.mode(),
+                attrs.uid,
+                attrs.gid,
+                attrs.size,
+                attrs.inode,
+                attrs.nlink,
+                attrs.mtime
+            ),
+            Err(e) => println!("{} {}: {}", name, path, e),
+        }
+    }
+}
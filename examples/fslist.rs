@@ -0,0 +1,13 @@
+extern crate sigar_rs;
+
+use sigar_rs::fs;
+
+fn main() {
+    let list = fs::list().unwrap();
+    for one in list {
+        println!(
+            "{} on {} type {} ({:?})",
+            one.dev_name, one.dir_name, one.sys_type_name, one.type_
+        );
+    }
+}
@@ -0,0 +1,26 @@
+extern crate sigar_rs;
+
+use sigar_rs::fs;
+use std::time::Duration;
+
+fn main() {
+    for one in fs::list().unwrap() {
+        if let Err(e) = fs::ping_with_timeout(&one, Duration::from_secs(2)) {
+            println!("{}: ping failed, {}", one.dir_name, e);
+            continue;
+        }
+
+        match fs::usage_with_timeout(&one.dir_name, Duration::from_secs(2)) {
+            Ok(usage) => println!("{}: {:.0}% used", one.dir_name, usage.use_percent * 100.0),
+            Err(ref e) if e.is_timeout() => println!("{}: hung, {}", one.dir_name, e),
+            Err(e) => println!("{}: {}", one.dir_name, e),
+        }
+    }
+
+    let err = fs::with_timeout("/", Duration::from_millis(100), |sigar| {
+        std::thread::sleep(Duration::from_secs(1));
+        sigar.fs_usage("/")
+    })
+    .unwrap_err();
+    println!("slow call: {} (timeout: {})", err, err.is_timeout());
+}
@@ -0,0 +1,46 @@
+extern crate sigar_rs;
+
+use sigar_rs::{disk::IoSampler, fs};
+use std::{env, thread, time::Duration};
+
+fn main() {
+    let mut names: Vec<String> = env::args().skip(1).collect();
+    if names.is_empty() {
+        names = fs::list()
+            .unwrap()
+            .into_iter()
+            .filter(|one| one.type_ == fs::FsType::LocalDisk)
+            .map(|one| one.dir_name)
+            .collect();
+    }
+
+    let mut sampler = IoSampler::new().unwrap();
+    for _ in 0..3 {
+        println!(
+            "{:<24} {:>8} {:>8} {:>12} {:>12} {:>8} {:>8} {:>6}",
+            "Device", "r/s", "w/s", "rB/s", "wB/s", "await", "aqu-sz", "%util"
+        );
+        for name in names.iter() {
+            let rate = match sampler.sample(name) {
+                Ok(rate) => rate,
+                Err(e) => {
+                    println!("{:<24} {}", name, e);
+                    continue;
+                }
+            };
+            println!(
+                "{:<24} {:>8.2} {:>8.2} {:>12.1} {:>12.1} {:>8.2} {:>8.2} {:>6.2}",
+                name,
+                rate.reads_per_sec,
+                rate.writes_per_sec,
+                rate.read_bytes_per_sec,
+                rate.write_bytes_per_sec,
+                rate.await_millis.unwrap_or(0.0),
+                rate.queue.unwrap_or(0.0),
+                rate.util_percent.unwrap_or(0.0)
+            );
+        }
+        println!();
+        thread::sleep(Duration::from_secs(1));
+    }
+}
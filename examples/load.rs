@@ -9,4 +9,7 @@ fn main() {
         "loadavg: {:?}, {:?}, {:?}",
         loadavg.avg_1m, loadavg.avg_5m, loadavg.avg_15m
     );
+
+    let uptime = load::uptime().unwrap();
+    println!("up {}, boot time: {:?}", uptime, uptime.boot_time);
 }
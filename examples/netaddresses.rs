@@ -0,0 +1,36 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+use std::env;
+
+fn main() {
+    let addresses = match env::args().nth(1) {
+        Some(name) => net::interface_addresses(&name).unwrap(),
+        None => net::interface_address_list().unwrap(),
+    };
+
+    for one in addresses {
+        let scope = match one.scope {
+            Some(scope) => scope.to_string(),
+            None => "-".to_string(),
+        };
+        println!(
+            "{:<10} {}/{} scope={} flags={:#04x}{}{}",
+            one.label,
+            one.address,
+            one.prefix_length,
+            scope,
+            one.flags.bits(),
+            if one.flags.is_temporary() {
+                " temporary"
+            } else {
+                ""
+            },
+            if one.flags.is_permanent() {
+                " permanent"
+            } else {
+                ""
+            }
+        );
+    }
+}
@@ -0,0 +1,12 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+
+fn main() {
+    for one in net::arp_list().unwrap() {
+        println!(
+            "{} at {} [{}] on {} flags {:#x}",
+            one.address, one.hwaddr, one.type_, one.ifname, one.flags
+        );
+    }
+}
@@ -27,23 +27,28 @@ fn main() {
         str::from_utf8(strip_bytes(&interface.description[..])).unwrap()
     );
 
-    println!("\thwaddr: {:?}", interface.hwaddr);
+    println!("\thwaddr: {}", interface.hwaddr);
 
-    println!("\taddress: {:?}", interface.address);
+    println!("\taddress: {}", interface.address);
 
-    println!("\tdestination: {:?}", interface.destination);
+    println!("\tdestination: {}", interface.destination);
 
-    println!("\tbroadcast: {:?}", interface.broadcast);
+    println!("\tbroadcast: {}", interface.broadcast);
 
-    println!("\tnetmask: {:?}", interface.netmask);
+    println!("\tnetmask: {}", interface.netmask);
 
-    println!("\taddress6: {:?}", interface.address6);
+    println!("\taddress6: {}", interface.address6);
 
     println!("\tprefix6_length: {:?}", interface.prefix6_length);
 
-    println!("\tscope6: {:?}", interface.scope6);
+    println!("\tscope6: {}", interface.scope6);
 
-    println!("\tflags: {:?}", interface.flags);
+    println!(
+        "\tflags: {} (up: {}, loopback: {})",
+        interface.flags,
+        interface.flags.is_up(),
+        interface.flags.is_loopback()
+    );
 
     println!("\tmtu: {:?}", interface.mtu);
 
@@ -0,0 +1,22 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+use std::collections::HashMap;
+
+fn main() {
+    let flags = net::FLAG_NETCONN_CLIENT | net::FLAG_NETCONN_SERVER | net::FLAG_NETCONN_TCP;
+
+    let mut peers: HashMap<net::NetAddress, usize> = HashMap::new();
+    net::connection_walk(flags, |conn| {
+        if let Some(addr) = conn.remote_socket_addr() {
+            println!("{:?} -> {}", conn.local_socket_addr(), addr);
+        }
+        *peers.entry(conn.remote_address).or_insert(0) += 1;
+        true
+    })
+    .unwrap();
+
+    for (addr, count) in peers {
+        println!("{:<40} {} (ip {:?})", addr, count, addr.ip());
+    }
+}
@@ -0,0 +1,26 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+
+fn main() {
+    let mut names = net::ServiceNames::new().unwrap();
+
+    for port in &[22u64, 443, 5432] {
+        println!("tcp/{}: {:?}", port, names.get(net::ConnType::TCP, *port));
+    }
+
+    let flags = net::FLAG_NETCONN_CLIENT | net::FLAG_NETCONN_SERVER | net::FLAG_NETCONN_TCP;
+    for conn in net::connection_list(flags).unwrap() {
+        let local = names.local(&conn).map(str::to_string);
+        let remote = names.remote(&conn).map(str::to_string);
+        println!(
+            "{}:{} ({}) -> {}:{} ({})",
+            conn.local_address,
+            conn.local_port,
+            local.as_ref().map_or("-", String::as_str),
+            conn.remote_address,
+            conn.remote_port,
+            remote.as_ref().map_or("-", String::as_str)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+use std::env;
+use std::net::IpAddr;
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let port: u64 = args.next().map(|p| p.parse().unwrap()).unwrap_or(443);
+    let address: IpAddr = args
+        .next()
+        .map(|a| a.parse().unwrap())
+        .unwrap_or_else(|| "0.0.0.0".parse().unwrap());
+
+    let flags = net::FLAG_NETCONN_CLIENT | net::FLAG_NETCONN_SERVER | net::FLAG_NETCONN_TCP;
+    let stat = net::stat_port(flags, address, port).unwrap();
+    println!("{}:{} {:?}", address, port, stat);
+}
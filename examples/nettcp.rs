@@ -0,0 +1,17 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+use std::{thread, time::Duration};
+
+fn main() {
+    let prev = net::tcp().unwrap();
+    println!("{:?}", prev);
+
+    thread::sleep(Duration::from_secs(1));
+    let cur = net::tcp().unwrap();
+    println!(
+        "segments out: {}, retransmitted: {:.2}%",
+        cur.out_segs.saturating_sub(prev.out_segs),
+        cur.retrans_rate(&prev) * 100.0
+    );
+}
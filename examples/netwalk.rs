@@ -0,0 +1,31 @@
+extern crate sigar_rs;
+
+use sigar_rs::net;
+
+fn main() {
+    let flags = net::FLAG_NETCONN_CLIENT | net::FLAG_NETCONN_SERVER | net::FLAG_NETCONN_TCP;
+
+    let mut total = 0;
+    let mut listening = 0;
+    net::connection_walk(flags, |conn| {
+        total += 1;
+        if let net::ConnSate::TCP_LISTEN = conn.state {
+            listening += 1;
+        }
+        true
+    })
+    .unwrap();
+    println!("tcp connections: {}, listening: {}", total, listening);
+
+    let mut first = Vec::new();
+    net::connection_walk(flags, |conn| {
+        first.push(conn.local_port);
+        first.len() < 3
+    })
+    .unwrap();
+    println!(
+        "local ports of the first {} connections: {:?}",
+        first.len(),
+        first
+    );
+}
@@ -0,0 +1,23 @@
+extern crate sigar_rs;
+
+use sigar_rs::nfs;
+use std::{thread, time::Duration};
+
+fn main() {
+    let interval = Duration::from_secs(1);
+
+    let prev = match nfs::client_v3() {
+        Ok(prev) => prev,
+        Err(e) => {
+            println!("nfs v3 client: {}", e);
+            return;
+        }
+    };
+    thread::sleep(interval);
+    let cur = nfs::client_v3().unwrap();
+
+    println!("nfs v3 client, {} ops total", cur.total());
+    for (name, rate) in cur.rates(&prev, interval) {
+        println!("{:<12} {:>10.1}/s", name, rate);
+    }
+}
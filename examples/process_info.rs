@@ -19,4 +19,13 @@ fn main() {
     println!("cred: {:?}", process::cred(pid).unwrap());
 
     println!("cred name: {:?}", process::cred_name(pid).unwrap());
+
+    let limits = process::resource_limits().unwrap();
+    let fd = process::fd(pid).unwrap();
+    println!(
+        "open files: {} of {:?}, within limit: {}",
+        fd.total,
+        limits.open_files.cur,
+        limits.open_files.cur.allows(fd.total)
+    );
 }
@@ -0,0 +1,20 @@
+extern crate sigar_rs;
+
+use sigar_rs::process;
+use std::{thread::sleep, time::Duration};
+
+fn main() {
+    let mut sampler = process::CPUSampler::new().unwrap();
+
+    // the first round only records the baseline
+    sampler.sample_all().unwrap();
+
+    sleep(Duration::from_secs(1));
+
+    let mut usages = sampler.sample_all().unwrap();
+    usages.sort_by(|a, b| b.1.percent.partial_cmp(&a.1.percent).unwrap());
+
+    for (pid, cpu) in usages.iter().take(10) {
+        println!("{:>8} {:>6.1}%", pid, cpu.percent * 100.0);
+    }
+}
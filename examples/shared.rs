@@ -0,0 +1,24 @@
+extern crate sigar_rs;
+
+use sigar_rs::SharedSigar;
+use std::thread;
+
+fn main() {
+    let sigar = SharedSigar::new().unwrap();
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let sigar = sigar.clone();
+            thread::spawn(move || {
+                let pid = std::process::id();
+                let cpu = sigar.with(|s| s.cpu()).unwrap();
+                let mem = sigar.with(|s| s.proc_mem(pid)).unwrap();
+                println!("#{} cpu: {:?}, mem: {:?}", i, cpu, mem);
+            })
+        })
+        .collect();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+}
@@ -0,0 +1,8 @@
+extern crate sigar_rs;
+
+use sigar_rs::sys;
+
+fn main() {
+    let info = sys::info().unwrap();
+    println!("sys info: {:?}", info);
+}
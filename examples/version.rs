@@ -0,0 +1,6 @@
+extern crate sigar_rs;
+
+fn main() {
+    let version = sigar_rs::version();
+    println!("version: {:?}", version);
+}
@@ -0,0 +1,10 @@
+extern crate sigar_rs;
+
+use sigar_rs::who;
+
+fn main() {
+    let users = who::list().unwrap();
+    for user in users {
+        println!("{:?}", user);
+    }
+}
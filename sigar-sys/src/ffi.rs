@@ -171,6 +171,9 @@ pub const SIGAR_PROC_STATE_STOP: u8 = 84u8;
 pub const SIGAR_PROC_STATE_ZOMBIE: u8 = 90u8;
 pub const SIGAR_PROC_STATE_IDLE: u8 = 68u8;
 pub const SIGAR_PROC_NAME_LEN: u32 = 128;
+#[cfg(target_os = "linux")]
+pub const SIGAR_FS_NAME_LEN: u32 = 4096;
+#[cfg(not(target_os = "linux"))]
 pub const SIGAR_FS_NAME_LEN: u32 = 1024;
 pub const SIGAR_FS_INFO_LEN: u32 = 256;
 pub const SIGAR_INET6_ADDRSTRLEN: u32 = 46;
@@ -3127,8 +3130,8 @@ pub type sigar_file_system_type_e = u32;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct sigar_file_system_t {
-    pub dir_name: [::std::os::raw::c_char; 1024usize],
-    pub dev_name: [::std::os::raw::c_char; 1024usize],
+    pub dir_name: [::std::os::raw::c_char; SIGAR_FS_NAME_LEN as usize],
+    pub dev_name: [::std::os::raw::c_char; SIGAR_FS_NAME_LEN as usize],
     pub type_name: [::std::os::raw::c_char; 256usize],
     pub sys_type_name: [::std::os::raw::c_char; 256usize],
     pub options: [::std::os::raw::c_char; 256usize],
@@ -3139,7 +3142,7 @@ pub struct sigar_file_system_t {
 fn bindgen_test_layout_sigar_file_system_t() {
     assert_eq!(
         ::std::mem::size_of::<sigar_file_system_t>(),
-        2832usize,
+        SIGAR_FS_NAME_LEN as usize * 2 + 784usize,
         concat!("Size of: ", stringify!(sigar_file_system_t))
     );
     assert_eq!(
@@ -3159,7 +3162,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<sigar_file_system_t>())).dev_name as *const _ as usize },
-        1024usize,
+        SIGAR_FS_NAME_LEN as usize,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -3169,7 +3172,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<sigar_file_system_t>())).type_name as *const _ as usize },
-        2048usize,
+        SIGAR_FS_NAME_LEN as usize * 2,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -3181,7 +3184,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
         unsafe {
             &(*(::std::ptr::null::<sigar_file_system_t>())).sys_type_name as *const _ as usize
         },
-        2304usize,
+        SIGAR_FS_NAME_LEN as usize * 2 + 256usize,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -3191,7 +3194,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<sigar_file_system_t>())).options as *const _ as usize },
-        2560usize,
+        SIGAR_FS_NAME_LEN as usize * 2 + 512usize,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -3201,7 +3204,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<sigar_file_system_t>())).type_ as *const _ as usize },
-        2816usize,
+        SIGAR_FS_NAME_LEN as usize * 2 + 768usize,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -3211,7 +3214,7 @@ fn bindgen_test_layout_sigar_file_system_t() {
     );
     assert_eq!(
         unsafe { &(*(::std::ptr::null::<sigar_file_system_t>())).flags as *const _ as usize },
-        2824usize,
+        SIGAR_FS_NAME_LEN as usize * 2 + 776usize,
         concat!(
             "Offset of field: ",
             stringify!(sigar_file_system_t),
@@ -42,8 +42,7 @@ impl Usage {
 
 /// Returns cpu usage
 pub fn get() -> SigarResult<Usage> {
-    let raw = ffi_wrap!(sigar_cpu_get, sigar_cpu_t)?;
-    Ok(Usage::from_raw(&raw))
+    Sigar::new()?.cpu()
 }
 
 /// CPU usage list
@@ -51,12 +50,7 @@ pub type UsageList = Vec<Usage>;
 
 /// Returns cpu usage list
 pub fn list() -> SigarResult<UsageList> {
-    ffi_wrap_destroy!(
-        sigar_cpu_list_get,
-        sigar_cpu_list_destroy,
-        sigar_cpu_list_t,
-        (|list: &sigar_cpu_list_t| ffi_extract_list!(list, Usage::from_raw))
-    )
+    Sigar::new()?.cpu_list()
 }
 
 /// CPU informations
@@ -78,27 +72,7 @@ pub type InfoList = Vec<Info>;
 
 /// Returns cpu info list
 pub fn info_list() -> SigarResult<InfoList> {
-    ffi_wrap_destroy!(
-        sigar_cpu_info_list_get,
-        sigar_cpu_info_list_destroy,
-        sigar_cpu_info_list_t,
-        (|list: &sigar_cpu_info_list_t| ffi_extract_list!(
-            list,
-            (|one: &sigar_cpu_info_t| value_convert!(
-            Info,
-            one,
-            mhz,
-            mhz_max,
-            mhz_min,
-            cache_size,
-            total_sockets,
-            total_cores,
-            cores_per_socket,
-            (vendor: chars_to_bytes(&one.vendor[..])),
-            (model: chars_to_bytes(&one.model[..])),
-        ))
-        ))
-    )
+    Sigar::new()?.cpu_info_list()
 }
 
 /// CPU usage percentage
@@ -112,3 +86,48 @@ pub fn calc_percent(prev: &Usage, curr: &Usage) -> UsagePercent {
 
     perc
 }
+
+impl Sigar {
+    /// Returns cpu usage
+    pub fn cpu(&self) -> SigarResult<Usage> {
+        let raw = ffi_wrap!(self, sigar_cpu_get, sigar_cpu_t)?;
+        Ok(Usage::from_raw(&raw))
+    }
+
+    /// Returns cpu usage list
+    pub fn cpu_list(&self) -> SigarResult<UsageList> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_cpu_list_get,
+            sigar_cpu_list_destroy,
+            sigar_cpu_list_t,
+            (|list: &sigar_cpu_list_t| ffi_extract_list!(list, Usage::from_raw))
+        )
+    }
+
+    /// Returns cpu info list
+    pub fn cpu_info_list(&self) -> SigarResult<InfoList> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_cpu_info_list_get,
+            sigar_cpu_info_list_destroy,
+            sigar_cpu_info_list_t,
+            (|list: &sigar_cpu_info_list_t| ffi_extract_list!(
+                list,
+                (|one: &sigar_cpu_info_t| value_convert!(
+                    Info,
+                    one,
+                    mhz,
+                    mhz_max,
+                    mhz_min,
+                    cache_size,
+                    total_sockets,
+                    total_cores,
+                    cores_per_socket,
+                    (vendor: chars_to_bytes(&one.vendor[..])),
+                    (model: chars_to_bytes(&one.model[..])),
+                ))
+            ))
+        )
+    }
+}
@@ -0,0 +1,316 @@
+//! Provides methods for gathering block device io statistics
+//!
+
+use super::{result::*, util::*};
+use sigar_sys::*;
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::time::{Duration, Instant};
+
+// C: sigar_disk_usage_get
+/// Io counters of a block device, times are in milliseconds.
+///
+/// Counters not available on current os are `u64::MAX`,
+/// `service_time` and `queue` are `-1.0` then.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DiskUsage {
+    pub reads: u64,
+    pub writes: u64,
+    pub write_bytes: u64,
+    pub read_bytes: u64,
+    /// time spent reading
+    pub rtime: u64,
+    /// time spent writing
+    pub wtime: u64,
+    /// weighted time spent doing io
+    pub qtime: u64,
+    /// time spent doing io
+    pub time: u64,
+    /// uptime in seconds when the counters were read
+    pub snaptime: u64,
+    /// average service time since the previous call on the same handle
+    pub service_time: f64,
+    /// average queue length since the previous call on the same handle
+    pub queue: f64,
+}
+
+impl DiskUsage {
+    pub(crate) fn from_raw(raw: &sigar_disk_usage_t) -> Self {
+        value_convert!(
+            DiskUsage,
+            raw,
+            reads,
+            writes,
+            write_bytes,
+            read_bytes,
+            rtime,
+            wtime,
+            qtime,
+            time,
+            snaptime,
+            service_time,
+            queue
+        )
+    }
+}
+
+/// Returns io counters for given device name or mount point,
+/// e.g. `/dev/sda` or `/`
+pub fn usage(name: &str) -> SigarResult<DiskUsage> {
+    Sigar::new()?.disk_usage(name)
+}
+
+/// Io rates of a block device between two samples, like `iostat -x`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IoRate {
+    /// interval the rates were calculated over
+    pub interval: Duration,
+    pub reads_per_sec: f64,
+    pub writes_per_sec: f64,
+    pub read_bytes_per_sec: f64,
+    pub write_bytes_per_sec: f64,
+    /// average milliseconds per completed io, including time queued
+    pub await_millis: Option<f64>,
+    pub read_await_millis: Option<f64>,
+    pub write_await_millis: Option<f64>,
+    /// average number of ios in flight
+    pub queue: Option<f64>,
+    /// percent of time the device was busy, from 0.0 to 100.0
+    pub util_percent: Option<f64>,
+}
+
+impl IoRate {
+    fn between(prev: &DiskUsage, cur: &DiskUsage, interval: Duration) -> Self {
+        let millis = interval.as_secs() as f64 * 1000.0 + f64::from(interval.subsec_millis());
+        if millis <= 0.0 {
+            return IoRate {
+                interval,
+                ..Default::default()
+            };
+        }
+
+        let per_sec = |prev: u64, cur: u64| match delta(prev, cur) {
+            Some(delta) => delta as f64 * 1000.0 / millis,
+            None => 0.0,
+        };
+        let per_io = |time: Option<u64>, ios: Option<u64>| match (time, ios) {
+            (Some(_), Some(0)) => Some(0.0),
+            (Some(time), Some(ios)) => Some(time as f64 / ios as f64),
+            _ => None,
+        };
+
+        let reads = delta(prev.reads, cur.reads);
+        let writes = delta(prev.writes, cur.writes);
+        let rtime = delta(prev.rtime, cur.rtime);
+        let wtime = delta(prev.wtime, cur.wtime);
+        let ios = reads.and_then(|r| writes.map(|w| r + w));
+        let io_time = rtime.and_then(|r| wtime.map(|w| r + w));
+
+        IoRate {
+            interval,
+            reads_per_sec: per_sec(prev.reads, cur.reads),
+            writes_per_sec: per_sec(prev.writes, cur.writes),
+            read_bytes_per_sec: per_sec(prev.read_bytes, cur.read_bytes),
+            write_bytes_per_sec: per_sec(prev.write_bytes, cur.write_bytes),
+            await_millis: per_io(io_time, ios),
+            read_await_millis: per_io(rtime, reads),
+            write_await_millis: per_io(wtime, writes),
+            queue: delta(prev.qtime, cur.qtime).map(|qtime| qtime as f64 / millis),
+            util_percent: delta(prev.time, cur.time)
+                .map(|time| (time as f64 * 100.0 / millis).min(100.0)),
+        }
+    }
+}
+
+/// Returns the increase of a counter, `None` if not available.
+/// Counters that went backwards (device reset) count from zero.
+fn delta(prev: u64, cur: u64) -> Option<u64> {
+    if prev == u64::MAX || cur == u64::MAX {
+        return None;
+    }
+
+    Some(counter_delta(prev, cur))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct IoSample {
+    usage: DiskUsage,
+    at: Instant,
+}
+
+/// Samples block device io counters, keeping the previous sample of each
+/// device to calculate rates from.
+///
+/// The first sample of a device is compared with zeroed counters at boot time,
+/// so it reports averages since boot, like the first report of `iostat`.
+#[derive(Debug)]
+pub struct IoSampler {
+    sigar: Sigar,
+    samples: HashMap<String, IoSample>,
+}
+
+impl IoSampler {
+    /// Returns a sampler with no previous samples
+    pub fn new() -> SigarResult<Self> {
+        Ok(IoSampler {
+            sigar: Sigar::new()?,
+            samples: HashMap::new(),
+        })
+    }
+
+    /// Returns io rates for given device name or mount point
+    ///
+    /// The previous sample of the device is dropped if it can't be read.
+    pub fn sample(&mut self, name: &str) -> SigarResult<IoRate> {
+        let usage = match self.sigar.disk_usage(name) {
+            Ok(usage) => usage,
+            Err(e) => {
+                self.samples.remove(name);
+                return Err(e);
+            }
+        };
+
+        let now = Instant::now();
+        let rate = match self.samples.get(name) {
+            Some(prev) => IoRate::between(&prev.usage, &usage, now - prev.at),
+            None => {
+                let uptime = self.sigar.uptime()?.duration;
+                IoRate::between(&DiskUsage::default(), &usage, uptime)
+            }
+        };
+
+        self.samples
+            .insert(name.to_string(), IoSample { usage, at: now });
+
+        Ok(rate)
+    }
+
+    /// Drops samples of devices not in given name list
+    pub fn retain(&mut self, names: &[&str]) {
+        self.samples
+            .retain(|name, _| names.contains(&name.as_str()));
+    }
+}
+
+impl Sigar {
+    /// Returns io counters for given device name or mount point
+    pub fn disk_usage(&self, name: &str) -> SigarResult<DiskUsage> {
+        let name_ptr = CString::new(name)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_disk_usage_get,
+            (name_ptr.as_ptr()),
+            sigar_disk_usage_t
+        )
+        .map_err(|e| e.with_name(name))?;
+
+        Ok(DiskUsage::from_raw(&raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(ios: u64, bytes: u64, io_time: u64, time: u64) -> DiskUsage {
+        DiskUsage {
+            reads: ios,
+            writes: ios * 2,
+            read_bytes: bytes,
+            write_bytes: bytes * 2,
+            rtime: io_time,
+            wtime: io_time * 2,
+            qtime: io_time * 3,
+            time,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn rates_between_samples() {
+        let prev = usage(100, 4096, 50, 100);
+        let cur = usage(300, 4096 * 3, 250, 600);
+        let rate = IoRate::between(&prev, &cur, Duration::from_secs(2));
+
+        assert_eq!(rate.interval, Duration::from_secs(2));
+        assert_eq!(rate.reads_per_sec, 100.0);
+        assert_eq!(rate.writes_per_sec, 200.0);
+        assert_eq!(rate.read_bytes_per_sec, 4096.0);
+        assert_eq!(rate.write_bytes_per_sec, 8192.0);
+        assert_eq!(rate.read_await_millis, Some(1.0));
+        assert_eq!(rate.write_await_millis, Some(1.0));
+        assert_eq!(rate.await_millis, Some(1.0));
+        assert_eq!(rate.queue, Some(0.3));
+        assert_eq!(rate.util_percent, Some(25.0));
+    }
+
+    #[test]
+    fn idle_device() {
+        let prev = usage(100, 4096, 50, 100);
+        let rate = IoRate::between(&prev, &prev, Duration::from_secs(1));
+
+        assert_eq!(rate.reads_per_sec, 0.0);
+        assert_eq!(rate.await_millis, Some(0.0));
+        assert_eq!(rate.util_percent, Some(0.0));
+    }
+
+    #[test]
+    fn util_is_capped() {
+        let prev = usage(0, 0, 0, 0);
+        let cur = usage(0, 0, 0, 1500);
+        let rate = IoRate::between(&prev, &cur, Duration::from_secs(1));
+        assert_eq!(rate.util_percent, Some(100.0));
+    }
+
+    #[test]
+    fn counter_reset_counts_from_zero() {
+        let prev = usage(1000, 8192, 500, 1000);
+        let cur = usage(10, 1024, 20, 100);
+        let rate = IoRate::between(&prev, &cur, Duration::from_secs(1));
+
+        assert_eq!(rate.reads_per_sec, 10.0);
+        assert_eq!(rate.read_bytes_per_sec, 1024.0);
+        assert_eq!(rate.read_await_millis, Some(2.0));
+        assert_eq!(rate.util_percent, Some(10.0));
+    }
+
+    #[test]
+    fn zero_interval() {
+        let prev = usage(100, 4096, 50, 100);
+        let cur = usage(300, 8192, 250, 600);
+        let rate = IoRate::between(&prev, &cur, Duration::from_secs(0));
+
+        assert_eq!(rate.reads_per_sec, 0.0);
+        assert_eq!(rate.await_millis, None);
+        assert_eq!(rate.util_percent, None);
+    }
+
+    #[test]
+    fn unavailable_counters() {
+        let prev = DiskUsage {
+            rtime: u64::MAX,
+            qtime: u64::MAX,
+            ..usage(100, 4096, 50, 100)
+        };
+        let cur = DiskUsage {
+            rtime: u64::MAX,
+            qtime: u64::MAX,
+            ..usage(300, 8192, 250, 600)
+        };
+        let rate = IoRate::between(&prev, &cur, Duration::from_secs(1));
+
+        assert_eq!(rate.reads_per_sec, 200.0);
+        assert_eq!(rate.read_await_millis, None);
+        assert_eq!(rate.await_millis, None);
+        assert_eq!(rate.write_await_millis, Some(1.0));
+        assert_eq!(rate.queue, None);
+    }
+
+    #[test]
+    fn delta_of_counters() {
+        assert_eq!(delta(5, 8), Some(3));
+        assert_eq!(delta(8, 5), Some(5));
+        assert_eq!(delta(u64::MAX, 5), None);
+        assert_eq!(delta(5, u64::MAX), None);
+    }
+}
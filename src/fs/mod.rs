@@ -0,0 +1,711 @@
+//! Provides methods for gathering file system informations
+//!
+
+pub use super::disk::DiskUsage;
+use super::{result::*, util::*};
+use sigar_sys::*;
+use std::ffi::CString;
+use std::fmt;
+use std::os::raw::c_char;
+use std::panic;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// C: sigar_file_system_list_get
+/// File system types, as classified by sigar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FsType {
+    Unknown,
+    /// virtual file systems, e.g. proc, sysfs or tmpfs on linux
+    None,
+    LocalDisk,
+    Network,
+    RamDisk,
+    Cdrom,
+    Swap,
+}
+
+impl FsType {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(raw: sigar_file_system_type_e) -> Self {
+        match raw {
+            sigar_file_system_type_e_SIGAR_FSTYPE_NONE => FsType::None,
+            sigar_file_system_type_e_SIGAR_FSTYPE_LOCAL_DISK => FsType::LocalDisk,
+            sigar_file_system_type_e_SIGAR_FSTYPE_NETWORK => FsType::Network,
+            sigar_file_system_type_e_SIGAR_FSTYPE_RAM_DISK => FsType::RamDisk,
+            sigar_file_system_type_e_SIGAR_FSTYPE_CDROM => FsType::Cdrom,
+            sigar_file_system_type_e_SIGAR_FSTYPE_SWAP => FsType::Swap,
+            _ => FsType::Unknown,
+        }
+    }
+
+    fn to_raw(self) -> sigar_file_system_type_e {
+        match self {
+            FsType::Unknown => sigar_file_system_type_e_SIGAR_FSTYPE_UNKNOWN,
+            FsType::None => sigar_file_system_type_e_SIGAR_FSTYPE_NONE,
+            FsType::LocalDisk => sigar_file_system_type_e_SIGAR_FSTYPE_LOCAL_DISK,
+            FsType::Network => sigar_file_system_type_e_SIGAR_FSTYPE_NETWORK,
+            FsType::RamDisk => sigar_file_system_type_e_SIGAR_FSTYPE_RAM_DISK,
+            FsType::Cdrom => sigar_file_system_type_e_SIGAR_FSTYPE_CDROM,
+            FsType::Swap => sigar_file_system_type_e_SIGAR_FSTYPE_SWAP,
+        }
+    }
+}
+
+/// A mounted file system
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    /// mount point
+    pub dir_name: String,
+    pub dev_name: String,
+    /// generic type name, e.g. `local` or `remote`
+    pub type_name: String,
+    /// os type name, e.g. `ext4` or `nfs`
+    pub sys_type_name: String,
+    pub options: String,
+    pub type_: FsType,
+    pub flags: u64,
+}
+
+impl FileSystem {
+    fn from_raw(raw: &sigar_file_system_t) -> Self {
+        value_convert!(
+            FileSystem,
+            raw,
+            flags,
+            (dir_name: chars_to_string(&raw.dir_name[..])),
+            (dev_name: chars_to_string(&raw.dev_name[..])),
+            (type_name: chars_to_string(&raw.type_name[..])),
+            (sys_type_name: chars_to_string(&raw.sys_type_name[..])),
+            (options: chars_to_string(&raw.options[..])),
+            (type_: FsType::from_raw(raw.type_)),
+        )
+    }
+
+    fn to_raw(&self) -> sigar_file_system_t {
+        let mut raw: sigar_file_system_t = Default::default();
+        string_to_chars(&self.dir_name, &mut raw.dir_name[..]);
+        string_to_chars(&self.dev_name, &mut raw.dev_name[..]);
+        string_to_chars(&self.type_name, &mut raw.type_name[..]);
+        string_to_chars(&self.sys_type_name, &mut raw.sys_type_name[..]);
+        string_to_chars(&self.options, &mut raw.options[..]);
+        raw.type_ = self.type_.to_raw();
+        raw.flags = self.flags as _;
+        raw
+    }
+}
+
+/// Returns mounted file systems
+pub fn list() -> SigarResult<Vec<FileSystem>> {
+    Sigar::new()?.fs_list()
+}
+
+impl Sigar {
+    /// Returns mounted file systems
+    pub fn fs_list(&self) -> SigarResult<Vec<FileSystem>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_file_system_list_get,
+            sigar_file_system_list_destroy,
+            sigar_file_system_list_t,
+            (|list: &sigar_file_system_list_t| ffi_extract_list!(list, FileSystem::from_raw))
+        )
+    }
+}
+
+// C: sigar_file_system_usage_get
+/// Space and inode usage of a mounted file system
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileSystemUsage {
+    /// io counters of the backing device
+    pub disk: DiskUsage,
+    /// used / (used + avail), from 0.0 to 1.0
+    pub use_percent: f64,
+    /// total bytes
+    pub total: u64,
+    /// free bytes, including those reserved for root
+    pub free: u64,
+    /// used bytes
+    pub used: u64,
+    /// bytes available to unprivileged users
+    pub avail: u64,
+    /// total inodes
+    pub files: u64,
+    /// free inodes
+    pub free_files: u64,
+}
+
+impl FileSystemUsage {
+    /// Returns used inodes / total inodes, from 0.0 to 1.0
+    pub fn files_use_percent(&self) -> f64 {
+        if self.files == 0 {
+            return 0.0;
+        }
+
+        self.files.saturating_sub(self.free_files) as f64 / self.files as f64
+    }
+
+    fn from_raw(raw: &sigar_file_system_usage_t) -> Self {
+        // sigar reports sizes in KB, sentinels saturate instead of wrapping
+        value_convert!(
+            FileSystemUsage,
+            raw,
+            use_percent,
+            files,
+            free_files,
+            (disk: DiskUsage::from_raw(&raw.disk)),
+            (total: raw.total.saturating_mul(1024)),
+            (free: raw.free.saturating_mul(1024)),
+            (used: raw.used.saturating_mul(1024)),
+            (avail: raw.avail.saturating_mul(1024)),
+        )
+    }
+}
+
+/// Returns usage of the file system mounted at `path`
+pub fn usage(path: &str) -> SigarResult<FileSystemUsage> {
+    Sigar::new()?.fs_usage(path)
+}
+
+/// Returns usage of the file system mounted at `path`,
+/// or an error for which [`Error::is_timeout`] is true if it takes longer than `timeout`
+///
+/// See [`with_timeout`] for how the call is made.
+///
+/// [`Error::is_timeout`]: ../struct.Error.html#method.is_timeout
+/// [`with_timeout`]: fn.with_timeout.html
+pub fn usage_with_timeout(path: &str, timeout: Duration) -> SigarResult<FileSystemUsage> {
+    let name = path.to_string();
+    with_timeout(path, timeout, move |sigar| sigar.fs_usage(&name))
+        .map_err(|e| e.with_op("sigar_file_system_usage_get").with_name(path))
+}
+
+impl Sigar {
+    /// Returns usage of the file system mounted at `path`
+    pub fn fs_usage(&self, path: &str) -> SigarResult<FileSystemUsage> {
+        let path_ptr = CString::new(path)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_file_system_usage_get,
+            (path_ptr.as_ptr()),
+            sigar_file_system_usage_t
+        )
+        .map_err(|e| e.with_name(path))?;
+
+        Ok(FileSystemUsage::from_raw(&raw))
+    }
+}
+
+// C: sigar_dir_stat_get
+/// Entry counts of a directory
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DirStat {
+    /// sum of all entries below
+    pub total: u64,
+    pub files: u64,
+    pub subdirs: u64,
+    pub symlinks: u64,
+    pub chrdevs: u64,
+    pub blkdevs: u64,
+    pub sockets: u64,
+    /// sum of entry sizes in bytes
+    pub disk_usage: u64,
+}
+
+impl DirStat {
+    fn from_raw(raw: &sigar_dir_stat_t) -> Self {
+        value_convert!(
+            DirStat, raw, total, files, subdirs, symlinks, chrdevs, blkdevs, sockets, disk_usage
+        )
+    }
+}
+
+/// Returns entry counts of `path`, not descending into subdirectories
+pub fn dir_stat(path: &str) -> SigarResult<DirStat> {
+    Sigar::new()?.dir_stat(path)
+}
+
+// C: sigar_dir_usage_get
+/// Returns entry counts of `path`, including all subdirectories
+///
+/// Subdirectories that can't be read are skipped.
+pub fn dir_usage(path: &str) -> SigarResult<DirStat> {
+    Sigar::new()?.dir_usage(path)
+}
+
+impl Sigar {
+    /// Returns entry counts of `path`, not descending into subdirectories
+    pub fn dir_stat(&self, path: &str) -> SigarResult<DirStat> {
+        let path_ptr = CString::new(path)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_dir_stat_get,
+            (path_ptr.as_ptr()),
+            sigar_dir_stat_t
+        )
+        .map_err(|e| e.with_name(path))?;
+
+        Ok(DirStat::from_raw(&raw))
+    }
+
+    /// Returns entry counts of `path`, including all subdirectories
+    pub fn dir_usage(&self, path: &str) -> SigarResult<DirStat> {
+        let path_ptr = CString::new(path)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_dir_usage_get,
+            (path_ptr.as_ptr()),
+            sigar_dir_usage_t
+        )
+        .map_err(|e| e.with_name(path))?;
+
+        Ok(DirStat::from_raw(&raw))
+    }
+}
+
+// C: sigar_file_attrs_get
+/// File types, as classified by sigar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileType {
+    NoFile,
+    Regular,
+    Directory,
+    CharDevice,
+    BlockDevice,
+    Pipe,
+    Symlink,
+    Socket,
+    Unknown,
+}
+
+impl FileType {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(raw: sigar_file_type_e) -> Self {
+        match raw {
+            sigar_file_type_e_SIGAR_FILETYPE_NOFILE => FileType::NoFile,
+            sigar_file_type_e_SIGAR_FILETYPE_REG => FileType::Regular,
+            sigar_file_type_e_SIGAR_FILETYPE_DIR => FileType::Directory,
+            sigar_file_type_e_SIGAR_FILETYPE_CHR => FileType::CharDevice,
+            sigar_file_type_e_SIGAR_FILETYPE_BLK => FileType::BlockDevice,
+            sigar_file_type_e_SIGAR_FILETYPE_PIPE => FileType::Pipe,
+            sigar_file_type_e_SIGAR_FILETYPE_LNK => FileType::Symlink,
+            sigar_file_type_e_SIGAR_FILETYPE_SOCK => FileType::Socket,
+            _ => FileType::Unknown,
+        }
+    }
+
+    fn to_raw(self) -> sigar_file_type_e {
+        match self {
+            FileType::NoFile => sigar_file_type_e_SIGAR_FILETYPE_NOFILE,
+            FileType::Regular => sigar_file_type_e_SIGAR_FILETYPE_REG,
+            FileType::Directory => sigar_file_type_e_SIGAR_FILETYPE_DIR,
+            FileType::CharDevice => sigar_file_type_e_SIGAR_FILETYPE_CHR,
+            FileType::BlockDevice => sigar_file_type_e_SIGAR_FILETYPE_BLK,
+            FileType::Pipe => sigar_file_type_e_SIGAR_FILETYPE_PIPE,
+            FileType::Symlink => sigar_file_type_e_SIGAR_FILETYPE_LNK,
+            FileType::Socket => sigar_file_type_e_SIGAR_FILETYPE_SOCK,
+            FileType::Unknown => sigar_file_type_e_SIGAR_FILETYPE_UNKFILE,
+        }
+    }
+}
+
+/// Formats as sigar names the type, e.g. `directory` or `symbolic link`
+impl fmt::Display for FileType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let name = ptr_to_string(unsafe { sigar_file_attrs_type_string_get(self.to_raw()) });
+        f.write_str(&name)
+    }
+}
+
+/// Attributes of a file
+#[derive(Debug, Clone, Copy)]
+pub struct FileAttrs {
+    /// sigar permission bits, see [`permissions_string`] and [`mode`]
+    ///
+    /// [`permissions_string`]: #method.permissions_string
+    /// [`mode`]: #method.mode
+    pub permissions: u64,
+    pub type_: FileType,
+    pub uid: u32,
+    pub gid: u32,
+    pub inode: u64,
+    /// id of the device the file is on
+    pub device: u64,
+    pub nlink: u64,
+    pub size: u64,
+    pub atime: SystemTime,
+    pub mtime: SystemTime,
+    pub ctime: SystemTime,
+}
+
+impl FileAttrs {
+    fn from_raw(raw: &sigar_file_attrs_t) -> Self {
+        // sigar reports times in milliseconds
+        let time = |millis: u64| UNIX_EPOCH + Duration::from_millis(millis);
+
+        value_convert!(
+            FileAttrs,
+            raw,
+            permissions,
+            uid,
+            gid,
+            inode,
+            device,
+            nlink,
+            size,
+            (type_: FileType::from_raw(raw.type_)),
+            (atime: time(raw.atime)),
+            (mtime: time(raw.mtime)),
+            (ctime: time(raw.ctime)),
+        )
+    }
+
+    /// Returns permissions like `ls -l` does, e.g. `rwxr-x---`
+    pub fn permissions_string(&self) -> String {
+        let mut buf = [0 as c_char; 10];
+        unsafe { sigar_file_attrs_permissions_string_get(self.permissions, buf.as_mut_ptr()) };
+        chars_to_string(&buf[..])
+    }
+
+    /// Returns permissions as unix mode, e.g. `0o750`
+    ///
+    /// sigar does not keep the setuid, setgid and sticky bits.
+    pub fn mode(&self) -> u32 {
+        digits_to_mode(unsafe { sigar_file_attrs_mode_get(self.permissions) } as u32)
+    }
+}
+
+/// Converts octal digits written as a decimal number, e.g. 750, to the mode they spell
+fn digits_to_mode(mut digits: u32) -> u32 {
+    let mut mode = 0;
+    let mut shift = 0;
+    while digits > 0 {
+        mode |= (digits % 10) << shift;
+        digits /= 10;
+        shift += 3;
+    }
+
+    mode
+}
+
+/// Returns attributes of `path`, following symbolic links
+pub fn file_attrs(path: &str) -> SigarResult<FileAttrs> {
+    Sigar::new()?.file_attrs(path)
+}
+
+// C: sigar_link_attrs_get
+/// Returns attributes of `path`, not following symbolic links
+pub fn link_attrs(path: &str) -> SigarResult<FileAttrs> {
+    Sigar::new()?.link_attrs(path)
+}
+
+impl Sigar {
+    /// Returns attributes of `path`, following symbolic links
+    pub fn file_attrs(&self, path: &str) -> SigarResult<FileAttrs> {
+        let path_ptr = CString::new(path)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_file_attrs_get,
+            (path_ptr.as_ptr()),
+            sigar_file_attrs_t
+        )
+        .map_err(|e| e.with_name(path))?;
+
+        Ok(FileAttrs::from_raw(&raw))
+    }
+
+    /// Returns attributes of `path`, not following symbolic links
+    pub fn link_attrs(&self, path: &str) -> SigarResult<FileAttrs> {
+        let path_ptr = CString::new(path)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_link_attrs_get,
+            (path_ptr.as_ptr()),
+            sigar_file_attrs_t
+        )
+        .map_err(|e| e.with_name(path))?;
+
+        Ok(FileAttrs::from_raw(&raw))
+    }
+}
+
+// C: sigar_file_system_ping
+/// Checks the server of `fs` answers
+///
+/// Only nfs mounts are checked, with an rpc ping to the server,
+/// the call is a no-op for any other file system.
+/// It blocks as long as the server takes to answer, see [`ping_with_timeout`].
+///
+/// [`ping_with_timeout`]: fn.ping_with_timeout.html
+pub fn ping(fs: &FileSystem) -> SigarResult<()> {
+    Sigar::new()?.fs_ping(fs)
+}
+
+/// Checks the server of `fs` answers within `timeout`,
+/// see [`ping`] and [`with_timeout`]
+///
+/// [`ping`]: fn.ping.html
+/// [`with_timeout`]: fn.with_timeout.html
+pub fn ping_with_timeout(fs: &FileSystem, timeout: Duration) -> SigarResult<()> {
+    let target = fs.clone();
+    with_timeout(&fs.dir_name, timeout, move |sigar| sigar.fs_ping(&target))
+        .map_err(|e| e.with_op("sigar_file_system_ping").with_name(&fs.dir_name))
+}
+
+/// Targets with a worker still running, see [`with_timeout`]
+///
+/// [`with_timeout`]: fn.with_timeout.html
+static PENDING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Marks a target pending until dropped by its worker
+struct Pending(String);
+
+impl Pending {
+    /// Returns `None` if a worker for `target` is still running
+    fn start(target: &str) -> Option<Self> {
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        if pending.iter().any(|one| one == target) {
+            return None;
+        }
+
+        pending.push(target.to_string());
+        Some(Pending(target.to_string()))
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        pending.retain(|one| *one != self.0);
+    }
+}
+
+/// Calls `f` on a worker thread with its own sigar handle, returning an error
+/// for which [`Error::is_timeout`] is true if it takes longer than `timeout`.
+///
+/// Calls like `statvfs` on a hung nfs mount can't be interrupted,
+/// so the worker is left blocked and exits whenever the call returns.
+/// `target` names what `f` touches, e.g. a mount point: while a worker for the
+/// same target is still blocked, calls fail at once with a timeout error
+/// reading "previous call on `target` still pending" instead of starting
+/// another worker, so at most one thread and sigar handle is stuck per target.
+/// A panic in `f` is resumed on the calling thread.
+///
+/// [`Error::is_timeout`]: ../struct.Error.html#method.is_timeout
+pub fn with_timeout<T, F>(target: &str, timeout: Duration, f: F) -> SigarResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&Sigar) -> SigarResult<T> + Send + 'static,
+{
+    let pending = match Pending::start(target) {
+        Some(pending) => pending,
+        None => return Err(Error::pending(target)),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let worker = thread::spawn(move || {
+        let result = Sigar::new().and_then(|sigar| f(&sigar));
+        // free the target before the caller sees the result, also dropped on panic
+        drop(pending);
+        let _ = tx.send(result);
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => Err(Error::timeout(timeout)),
+        Err(RecvTimeoutError::Disconnected) => match worker.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(()) => unreachable!("worker exited without sending its result"),
+        },
+    }
+}
+
+impl Sigar {
+    /// Checks the server of `fs` answers, see [`ping`]
+    ///
+    /// [`ping`]: fn.ping.html
+    pub fn fs_ping(&self, fs: &FileSystem) -> SigarResult<()> {
+        let mut raw = fs.to_raw();
+        let res = unsafe { sigar_file_system_ping(self.ptr, &mut raw) };
+        if res == SIGAR_CODE_OK {
+            return Ok(());
+        }
+
+        #[cfg(unix)]
+        let err = Error::rpc(res);
+        #[cfg(not(unix))]
+        let err = Error::new(self.ptr, res);
+
+        Err(err
+            .with_op("sigar_file_system_ping")
+            .with_name(&fs.dir_name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::time::Instant;
+
+    #[test]
+    fn mode_from_digits() {
+        assert_eq!(digits_to_mode(755), 0o755);
+        assert_eq!(digits_to_mode(644), 0o644);
+        assert_eq!(digits_to_mode(4755), 0o4755);
+        assert_eq!(digits_to_mode(1777), 0o1777);
+        assert_eq!(digits_to_mode(7), 0o7);
+        assert_eq!(digits_to_mode(0), 0);
+    }
+
+    #[test]
+    fn mode_from_permissions() {
+        let attrs = |permissions| FileAttrs {
+            permissions,
+            ..FileAttrs::from_raw(&Default::default())
+        };
+        let rwxr_xr_x = SIGAR_UREAD
+            | SIGAR_UWRITE
+            | SIGAR_UEXECUTE
+            | SIGAR_GREAD
+            | SIGAR_GEXECUTE
+            | SIGAR_WREAD
+            | SIGAR_WEXECUTE;
+        assert_eq!(attrs(u64::from(rwxr_xr_x)).mode(), 0o755);
+        let rw_r = SIGAR_UREAD | SIGAR_UWRITE | SIGAR_GREAD;
+        assert_eq!(attrs(u64::from(rw_r)).mode(), 0o640);
+        assert_eq!(attrs(0).mode(), 0);
+    }
+
+    #[test]
+    fn usage_sizes_in_bytes() {
+        let raw = sigar_file_system_usage_t {
+            total: 100,
+            free: 40,
+            used: 60,
+            avail: 30,
+            ..Default::default()
+        };
+        let usage = FileSystemUsage::from_raw(&raw);
+        assert_eq!(usage.total, 102_400);
+        assert_eq!(usage.free, 40_960);
+        assert_eq!(usage.used, 61_440);
+        assert_eq!(usage.avail, 30_720);
+    }
+
+    #[test]
+    fn usage_sizes_saturate() {
+        let raw = sigar_file_system_usage_t {
+            total: u64::MAX,
+            avail: u64::MAX / 1024 + 1,
+            ..Default::default()
+        };
+        let usage = FileSystemUsage::from_raw(&raw);
+        assert_eq!(usage.total, u64::MAX);
+        assert_eq!(usage.avail, u64::MAX);
+    }
+
+    #[test]
+    fn files_use_percent() {
+        let usage = FileSystemUsage {
+            files: 200,
+            free_files: 150,
+            ..Default::default()
+        };
+        assert_eq!(usage.files_use_percent(), 0.25);
+    }
+
+    #[test]
+    fn files_use_percent_with_bogus_counts() {
+        let usage = FileSystemUsage {
+            files: 100,
+            free_files: 150,
+            ..Default::default()
+        };
+        assert_eq!(usage.files_use_percent(), 0.0);
+        assert_eq!(FileSystemUsage::default().files_use_percent(), 0.0);
+    }
+
+    #[test]
+    fn timeout_blocks_target_until_worker_returns() {
+        let timeout = Duration::from_millis(50);
+        let (release, blocked) = mpsc::channel::<()>();
+        let err = with_timeout("test-hung", timeout, move |_| {
+            let _ = blocked.recv();
+            Ok(())
+        })
+        .unwrap_err();
+        assert!(err.is_timeout());
+
+        // no second worker while the first is stuck
+        let called = Arc::new(AtomicBool::new(false));
+        let flag = called.clone();
+        let err = with_timeout("test-hung", timeout, move |_| {
+            flag.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .unwrap_err();
+        assert!(err.is_timeout());
+
+        // other targets are not blocked
+        assert_eq!(with_timeout("test-other", timeout, |_| Ok(1)).unwrap(), 1);
+
+        release.send(()).unwrap();
+        let mut result = Err(Error::timeout(timeout));
+        for _ in 0..100 {
+            result = with_timeout("test-hung", timeout, |_| Ok(2));
+            if result.is_ok() {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(result.unwrap(), 2);
+        assert!(!called.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn pending_call_fails_at_once() {
+        let (release, blocked) = mpsc::channel::<()>();
+        let err = with_timeout("test-pending", Duration::from_millis(50), move |_| {
+            let _ = blocked.recv();
+            Ok(())
+        })
+        .unwrap_err();
+        assert!(err.is_timeout());
+        assert_eq!(err.reason(), "timed out after 50ms");
+
+        let start = Instant::now();
+        let err = with_timeout("test-pending", Duration::from_secs(10), |_| Ok(())).unwrap_err();
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert!(err.is_timeout());
+        assert_eq!(err.reason(), "previous call on test-pending still pending");
+
+        release.send(()).unwrap();
+    }
+
+    #[test]
+    fn worker_panic_clears_target() {
+        let timeout = Duration::from_secs(5);
+        let res = panic::catch_unwind(|| {
+            with_timeout("test-panic", timeout, |_| -> SigarResult<()> {
+                panic!("boom")
+            })
+        });
+        assert!(res.is_err());
+        assert_eq!(with_timeout("test-panic", timeout, |_| Ok(1)).unwrap(), 1);
+    }
+
+    #[test]
+    fn target_free_once_result_is_returned() {
+        let timeout = Duration::from_secs(5);
+        for i in 0..100 {
+            assert_eq!(
+                with_timeout("test-sequential", timeout, move |_| Ok(i)).unwrap(),
+                i
+            );
+        }
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(feature = "log")]
+use super::logging;
+use super::result::{Error, SigarResult};
+use sigar_sys::{sigar_close, sigar_open, sigar_t, SIGAR_CODE_OK};
+use std;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// An opened sigar instance.
+///
+/// sigar keeps internal caches (process cpu, pid list, disk io, boot time)
+/// inside its handle, so polling through one long-lived `Sigar` avoids
+/// re-opening the library on every call, as the free functions do.
+///
+/// sigar is not re-entrant, so a `Sigar` can be moved to another thread but
+/// not used from several threads at once, see [`SharedSigar`] for that.
+///
+/// [`SharedSigar`]: struct.SharedSigar.html
+#[derive(Debug)]
+pub struct Sigar {
+    pub(crate) ptr: *mut sigar_t,
+}
+
+// sigar_t holds no thread affine state, it only needs exclusive access.
+unsafe impl Send for Sigar {}
+
+impl Sigar {
+    /// Opens a new sigar instance
+    ///
+    /// With the `log` feature, sigar messages are forwarded to the `log` crate,
+    /// at the max level of `log` when opened.
+    pub fn new() -> SigarResult<Self> {
+        let mut ptr: *mut sigar_t = std::ptr::null_mut();
+
+        let res = unsafe { sigar_open(&mut ptr) };
+        if res != SIGAR_CODE_OK {
+            return Err(Error::new(ptr, res));
+        }
+
+        #[cfg(feature = "log")]
+        logging::install(ptr);
+
+        Ok(Sigar { ptr })
+    }
+}
+
+impl Drop for Sigar {
+    fn drop(&mut self) {
+        unsafe { sigar_close(self.ptr) };
+    }
+}
+
+/// A `Sigar` shared between threads.
+///
+/// Clones share the same handle, and calls are serialized by a mutex.
+#[derive(Debug, Clone)]
+pub struct SharedSigar {
+    inner: Arc<Mutex<Sigar>>,
+}
+
+impl SharedSigar {
+    /// Opens a new sigar instance for sharing
+    pub fn new() -> SigarResult<Self> {
+        Ok(SharedSigar::from(Sigar::new()?))
+    }
+
+    /// Locks the handle, for several calls in a row
+    pub fn lock(&self) -> MutexGuard<'_, Sigar> {
+        // a panic while holding the lock can't leave sigar_t in a broken state
+        match self.inner.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// Calls `f` with the locked handle
+    pub fn with<T, F: FnOnce(&Sigar) -> T>(&self, f: F) -> T {
+        f(&self.lock())
+    }
+}
+
+impl From<Sigar> for SharedSigar {
+    fn from(sigar: Sigar) -> Self {
+        SharedSigar {
+            inner: Arc::new(Mutex::new(sigar)),
+        }
+    }
+}
@@ -2,18 +2,36 @@
 
 extern crate sigar_sys;
 
+#[cfg(unix)]
+extern crate libc;
+
+#[cfg(feature = "log")]
+#[macro_use]
+extern crate log;
+
+mod handle;
+mod logging;
 mod result;
 mod util;
 pub use util::strip_bytes;
 mod integer;
 
-pub use result::{Error, SigarResult};
+pub use handle::{SharedSigar, Sigar};
+pub use logging::LogLevel;
+pub use result::{Error, ErrorKind, SigarResult, Target};
+pub use version::{version, Version};
 
 #[macro_use]
 mod macros;
+mod version;
 
 pub mod cpu;
+pub mod disk;
+pub mod fs;
 pub mod load;
 pub mod mem;
 pub mod net;
+pub mod nfs;
 pub mod process;
+pub mod sys;
+pub mod who;
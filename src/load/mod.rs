@@ -1,7 +1,10 @@
-//! Show loadavg
+//! Show loadavg and uptime
 
 use super::{result::*, util::*};
 use sigar_sys::*;
+use std::fmt;
+use std::os::raw::{c_char, c_int};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Load avg info
 #[derive(Debug)]
@@ -13,10 +16,59 @@ pub struct Load {
 
 /// Returns loadavg
 pub fn get() -> SigarResult<Load> {
-    let raw = ffi_wrap!(sigar_loadavg_get, sigar_loadavg_t)?;
-    Ok(Load {
-        avg_1m: raw.loadavg[0],
-        avg_5m: raw.loadavg[1],
-        avg_15m: raw.loadavg[2],
-    })
+    Sigar::new()?.load()
+}
+
+// C: sigar_uptime_get
+/// System uptime
+#[derive(Debug, Clone)]
+pub struct Uptime {
+    pub duration: Duration,
+    pub boot_time: SystemTime,
+    /// formatted by sigar_uptime_string, e.g. `12 days,  3:04`
+    pub formatted: String,
+}
+
+impl fmt::Display for Uptime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&self.formatted)
+    }
+}
+
+/// Returns system uptime
+pub fn uptime() -> SigarResult<Uptime> {
+    Sigar::new()?.uptime()
+}
+
+impl Sigar {
+    /// Returns loadavg
+    pub fn load(&self) -> SigarResult<Load> {
+        let raw = ffi_wrap!(self, sigar_loadavg_get, sigar_loadavg_t)?;
+        Ok(Load {
+            avg_1m: raw.loadavg[0],
+            avg_5m: raw.loadavg[1],
+            avg_15m: raw.loadavg[2],
+        })
+    }
+
+    /// Returns system uptime
+    pub fn uptime(&self) -> SigarResult<Uptime> {
+        let mut raw = ffi_wrap!(self, sigar_uptime_get, sigar_uptime_t)?;
+
+        let mut buf = [0 as c_char; 256];
+        unsafe {
+            sigar_uptime_string(self.ptr, &mut raw, buf.as_mut_ptr(), buf.len() as c_int);
+        }
+
+        let duration = Duration::from_millis((raw.uptime * 1000.0) as u64);
+        let boot_time = SystemTime::now()
+            .checked_sub(duration)
+            .unwrap_or(UNIX_EPOCH);
+
+        Ok(Uptime {
+            duration,
+            boot_time,
+            formatted: chars_to_string(&buf[..]),
+        })
+    }
 }
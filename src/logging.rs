@@ -0,0 +1,128 @@
+use super::util::*;
+use sigar_sys::*;
+use std::os::raw::c_int;
+
+/// Log levels of sigar
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Fatal,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn from_raw(raw: c_int) -> Option<Self> {
+        if raw < 0 {
+            return None;
+        }
+
+        match raw as u32 {
+            SIGAR_LOG_FATAL => Some(LogLevel::Fatal),
+            SIGAR_LOG_ERROR => Some(LogLevel::Error),
+            SIGAR_LOG_WARN => Some(LogLevel::Warn),
+            SIGAR_LOG_INFO => Some(LogLevel::Info),
+            SIGAR_LOG_DEBUG => Some(LogLevel::Debug),
+            _ => Some(LogLevel::Trace),
+        }
+    }
+
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            LogLevel::Fatal => SIGAR_LOG_FATAL,
+            LogLevel::Error => SIGAR_LOG_ERROR,
+            LogLevel::Warn => SIGAR_LOG_WARN,
+            LogLevel::Info => SIGAR_LOG_INFO,
+            LogLevel::Debug => SIGAR_LOG_DEBUG,
+            LogLevel::Trace => SIGAR_LOG_TRACE,
+        };
+
+        raw as c_int
+    }
+}
+
+impl Sigar {
+    /// Returns the log level, `None` if logging is off
+    pub fn log_level(&self) -> Option<LogLevel> {
+        LogLevel::from_raw(unsafe { sigar_log_level_get(self.ptr) })
+    }
+
+    /// Sets the log level, `None` turns logging off
+    pub fn set_log_level(&self, level: Option<LogLevel>) {
+        let raw = level.map(LogLevel::to_raw).unwrap_or(-1);
+        unsafe { sigar_log_level_set(self.ptr, raw) };
+    }
+}
+
+#[cfg(feature = "log")]
+pub(crate) use self::forward::install;
+
+/// Forwards sigar messages to the `log` crate, under the `sigar` target.
+/// `tracing` subscribers receive them through `tracing-log`.
+#[cfg(feature = "log")]
+mod forward {
+    use super::LogLevel;
+    use log;
+    use sigar_sys::*;
+    use std::ffi::CStr;
+    use std::os::raw::{c_char, c_int, c_void};
+    use std::panic;
+
+    impl LogLevel {
+        fn from_log(level: log::LevelFilter) -> Option<Self> {
+            match level {
+                log::LevelFilter::Off => None,
+                log::LevelFilter::Error => Some(LogLevel::Error),
+                log::LevelFilter::Warn => Some(LogLevel::Warn),
+                log::LevelFilter::Info => Some(LogLevel::Info),
+                log::LevelFilter::Debug => Some(LogLevel::Debug),
+                log::LevelFilter::Trace => Some(LogLevel::Trace),
+            }
+        }
+
+        fn to_log(self) -> log::Level {
+            match self {
+                LogLevel::Fatal | LogLevel::Error => log::Level::Error,
+                LogLevel::Warn => log::Level::Warn,
+                LogLevel::Info => log::Level::Info,
+                LogLevel::Debug => log::Level::Debug,
+                LogLevel::Trace => log::Level::Trace,
+            }
+        }
+    }
+
+    /// Called by sigar, a panicking logger drops the message
+    /// instead of unwinding into C
+    unsafe extern "C" fn forward(
+        _sigar: *mut sigar_t,
+        _data: *mut c_void,
+        level: c_int,
+        message: *mut c_char,
+    ) {
+        let level = match LogLevel::from_raw(level) {
+            Some(level) => level.to_log(),
+            None => return,
+        };
+
+        if message.is_null() {
+            return;
+        }
+
+        let message = CStr::from_ptr(message).to_string_lossy();
+        let _ = panic::catch_unwind(|| log!(target: "sigar", level, "{}", message));
+    }
+
+    /// Installs the forwarding callback, following the current max level of `log`
+    pub(crate) fn install(ptr: *mut sigar_t) {
+        let level = LogLevel::from_log(log::max_level())
+            .map(LogLevel::to_raw)
+            .unwrap_or(-1);
+
+        unsafe {
+            sigar_log_impl_set(ptr, ::std::ptr::null_mut(), Some(forward));
+            sigar_log_level_set(ptr, level);
+        }
+    }
+}
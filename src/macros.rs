@@ -1,24 +1,24 @@
 macro_rules! ffi_wrap {
-    ($fname:ident, $target:ident) => {{
+    ($sigar:expr, $fname:ident, $target:ident) => {{
         let result: SigarResult<$target> = unsafe {
-            let sigar_ptr = SigarPtr::new()?;
+            let sigar_ptr = $sigar;
 
             let mut info: $target = Default::default();
 
             let res = $fname(sigar_ptr.ptr, &mut info);
             if res != SIGAR_CODE_OK {
-                return Err(Error::new(sigar_ptr.ptr, res));
+                Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($fname)))
+            } else {
+                Ok(info)
             }
-
-            Ok(info)
         };
 
         result
     }};
 
-    ($fname:ident, ($($arg:expr), +), $target:ident) => {{
+    ($sigar:expr, $fname:ident, ($($arg:expr), +), $target:ident) => {{
         let result: SigarResult<$target> = unsafe {
-            let sigar_ptr = SigarPtr::new()?;
+            let sigar_ptr = $sigar;
 
             let mut info: $target = Default::default();
 
@@ -30,18 +30,18 @@ macro_rules! ffi_wrap {
                 &mut info,
             );
             if res != SIGAR_CODE_OK {
-                return Err(Error::new(sigar_ptr.ptr, res));
+                Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($fname)))
+            } else {
+                Ok(info)
             }
-
-            Ok(info)
         };
 
         result
     }};
 
-    ($func:tt, $target:ident) => {{
+    ($sigar:expr, $op:ident => $func:tt, $target:ident) => {{
         let result: SigarResult<$target> = unsafe {
-            let sigar_ptr = SigarPtr::new()?;
+            let sigar_ptr = $sigar;
 
             let mut info: $target = Default::default();
 
@@ -50,10 +50,10 @@ macro_rules! ffi_wrap {
                 &mut info,
             );
             if res != SIGAR_CODE_OK {
-                return Err(Error::new(sigar_ptr.ptr, res));
+                Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($op)))
+            } else {
+                Ok(info)
             }
-
-            Ok(info)
         };
 
         result
@@ -61,54 +61,54 @@ macro_rules! ffi_wrap {
 }
 
 macro_rules! ffi_wrap_destroy {
-    ($fnget:ident, $fndestroy:ident, $target:ident, $trans:tt) => {
+    ($sigar:expr, $fnget:ident, $fndestroy:ident, $target:ident, $trans:tt) => {
         unsafe {
-            let sigar_ptr = SigarPtr::new()?;
+            let sigar_ptr = $sigar;
 
             let mut info: $target = Default::default();
 
             let res = $fnget(sigar_ptr.ptr, &mut info);
             if res != SIGAR_CODE_OK {
-                return Err(Error::new(sigar_ptr.ptr, res));
-            }
-
-            let entity = $trans(&info);
-
-            let res = $fndestroy(sigar_ptr.ptr, &mut info);
-            if res != SIGAR_CODE_OK {
-                return Err(Error::from_str("failed to destroy sigar"));
+                Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($fnget)))
+            } else {
+                let entity = $trans(&info);
+
+                let res = $fndestroy(sigar_ptr.ptr, &mut info);
+                if res != SIGAR_CODE_OK {
+                    Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($fndestroy)))
+                } else {
+                    Ok(entity)
+                }
             }
-
-            Ok(entity)
         }
     };
 
-    ($fnget:tt, $fndestroy:ident, $target:ident, $trans:tt) => {
+    ($sigar:expr, $op:ident => $fnget:tt, $fndestroy:ident, $target:ident, $trans:tt) => {
         unsafe {
-            let sigar_ptr = SigarPtr::new()?;
+            let sigar_ptr = $sigar;
 
             let mut info: $target = Default::default();
 
             let res = $fnget(sigar_ptr.ptr, &mut info);
             if res != SIGAR_CODE_OK {
-                return Err(Error::new(sigar_ptr.ptr, res));
-            }
-
-            let entity = $trans(&info);
-
-            let res = $fndestroy(sigar_ptr.ptr, &mut info);
-            if res != SIGAR_CODE_OK {
-                return Err(Error::from_str("failed to destroy sigar"));
+                Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($op)))
+            } else {
+                let entity = $trans(&info);
+
+                let res = $fndestroy(sigar_ptr.ptr, &mut info);
+                if res != SIGAR_CODE_OK {
+                    Err(Error::new(sigar_ptr.ptr, res).with_op(stringify!($fndestroy)))
+                } else {
+                    Ok(entity)
+                }
             }
-
-            Ok(entity)
         }
     };
 }
 
 macro_rules! ffi_wrap_sigar_t {
-    ($func:tt) => {{
-        let sigar_ptr = SigarPtr::new()?;
+    ($sigar:expr, $func:tt) => {{
+        let sigar_ptr = $sigar;
 
         let ret = $func(sigar_ptr.ptr);
 
@@ -20,19 +20,7 @@ pub struct Mem {
 
 /// Returns memory information
 pub fn get() -> SigarResult<Mem> {
-    let raw = ffi_wrap!(sigar_mem_get, sigar_mem_t)?;
-    Ok(value_convert!(
-        Mem,
-        raw,
-        ram,
-        total,
-        used,
-        free,
-        actual_used,
-        actual_free,
-        used_percent,
-        free_percent,
-    ))
+    Sigar::new()?.mem()
 }
 
 /// Swap usage
@@ -47,8 +35,32 @@ pub struct Swap {
 
 /// Returns swap usage
 pub fn swap() -> SigarResult<Swap> {
-    let raw = ffi_wrap!(sigar_swap_get, sigar_swap_t)?;
-    Ok(value_convert!(
-        Swap, raw, total, used, free, page_in, page_out,
-    ))
+    Sigar::new()?.swap()
+}
+
+impl Sigar {
+    /// Returns memory information
+    pub fn mem(&self) -> SigarResult<Mem> {
+        let raw = ffi_wrap!(self, sigar_mem_get, sigar_mem_t)?;
+        Ok(value_convert!(
+            Mem,
+            raw,
+            ram,
+            total,
+            used,
+            free,
+            actual_used,
+            actual_free,
+            used_percent,
+            free_percent,
+        ))
+    }
+
+    /// Returns swap usage
+    pub fn swap(&self) -> SigarResult<Swap> {
+        let raw = ffi_wrap!(self, sigar_swap_get, sigar_swap_t)?;
+        Ok(value_convert!(
+            Swap, raw, total, used, free, page_in, page_out,
+        ))
+    }
 }
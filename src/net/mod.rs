@@ -3,10 +3,16 @@
 
 use super::{result::*, util::*};
 use sigar_sys::*;
-use std::error::Error as stdError;
+use std::any::Any;
+use std::collections::HashMap;
 use std::ffi::{CStr, CString};
+use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::net;
-use std::os::raw::{c_int, c_ulong};
+use std::ops;
+use std::os::raw::{c_char, c_int, c_ulong, c_void};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
 
 // C: sigar_net_info_get
 /// net info
@@ -22,19 +28,10 @@ pub struct Info {
 
 /// Returns net info
 pub fn info() -> SigarResult<Info> {
-    let raw = ffi_wrap!(sigar_net_info_get, sigar_net_info_t)?;
-
-    Ok(Info {
-        default_gateway: chars_to_bytes(&raw.default_gateway[..]),
-        default_gateway_interface: chars_to_bytes(&raw.default_gateway_interface[..]),
-        host_name: chars_to_bytes(&raw.host_name[..]),
-        domain_name: chars_to_bytes(&raw.domain_name[..]),
-        primary_dns: chars_to_bytes(&raw.primary_dns[..]),
-        secondary_dns: chars_to_bytes(&raw.secondary_dns[..]),
-    })
+    Sigar::new()?.net_info()
 }
 // C: sigar_net_route_list_get
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AFFamily {
     UNSPEC,
     INET,
@@ -52,9 +49,21 @@ impl AFFamily {
             _ => AFFamily::UNSPEC,
         }
     }
+
+    fn to_raw(self) -> sigar_net_address_t__bindgen_ty_1 {
+        match self {
+            AFFamily::UNSPEC => sigar_net_address_t_SIGAR_AF_UNSPEC,
+            AFFamily::INET => sigar_net_address_t_SIGAR_AF_INET,
+            AFFamily::INET6 => sigar_net_address_t_SIGAR_AF_INET6,
+            AFFamily::LINK => sigar_net_address_t_SIGAR_AF_LINK,
+        }
+    }
 }
 
-#[derive(Debug)]
+/// Raw address, read as each of the families, see [`NetAddress`] to get the right one
+///
+/// [`NetAddress`]: struct.NetAddress.html
+#[derive(Debug, Clone, Copy)]
 pub struct Address {
     inet4: net::Ipv4Addr,
     inet6: net::Ipv6Addr,
@@ -63,35 +72,44 @@ pub struct Address {
 
 impl Address {
     fn from_raw(raw: &sigar_net_address_t__bindgen_ty_2) -> Address {
-        const MASK_U16: u32 = !(0u32) >> 16;
-
+        // sigar keeps addresses in network byte order
         unsafe {
-            let in6: [u32; 4] = [
-                u32_reverse(raw.in6[0]),
-                u32_reverse(raw.in6[1]),
-                u32_reverse(raw.in6[2]),
-                u32_reverse(raw.in6[3]),
-            ];
+            let mut in6 = [0u8; 16];
+            for (i, one) in raw.in6.iter().enumerate() {
+                in6[i * 4..i * 4 + 4].copy_from_slice(&one.to_ne_bytes());
+            }
 
             Address {
-                inet4: net::Ipv4Addr::from(u32_reverse(raw.in_)),
-                inet6: net::Ipv6Addr::new(
-                    (in6[0] >> 16) as u16,
-                    (in6[0] & MASK_U16) as u16,
-                    (in6[1] >> 16) as u16,
-                    (in6[1] & MASK_U16) as u16,
-                    (in6[2] >> 16) as u16,
-                    (in6[2] & MASK_U16) as u16,
-                    (in6[3] >> 16) as u16,
-                    (in6[3] & MASK_U16) as u16,
-                ),
-                mac: raw.mac.clone(),
+                inet4: net::Ipv4Addr::from(raw.in_.to_ne_bytes()),
+                inet6: net::Ipv6Addr::from(in6),
+                mac: raw.mac,
             }
         }
     }
 }
 
-#[derive(Debug)]
+/// A mac address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MacAddr(pub [u8; 6]);
+
+/// Formats like sigar does, e.g. `02:FC:00:00:00:05`
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let m = &self.0;
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+            m[0], m[1], m[2], m[3], m[4], m[5]
+        )
+    }
+}
+
+/// An address as reported by sigar, an ip or mac address depending on `family`
+///
+/// Equality and hashing follow sigar_net_address_equals and sigar_net_address_hash,
+/// except that `UNSPEC` addresses are all equal, which sigar never reports,
+/// and that `LINK` addresses only compare the 6 bytes of the mac.
+#[derive(Debug, Clone, Copy)]
 pub struct NetAddress {
     pub family: AFFamily,
     pub address: Address,
@@ -104,6 +122,133 @@ impl NetAddress {
             address: Address::from_raw(&raw.addr),
         }
     }
+
+    fn to_raw(self) -> sigar_net_address_t {
+        let mut raw = sigar_net_address_t {
+            family: self.family.to_raw(),
+            ..Default::default()
+        };
+
+        match self.family {
+            AFFamily::INET => raw.addr.in_ = u32::from_ne_bytes(self.address.inet4.octets()),
+            AFFamily::INET6 => {
+                let octets = self.address.inet6.octets();
+                let mut in6 = [0u32; 4];
+                for (i, one) in in6.iter_mut().enumerate() {
+                    let mut bytes = [0u8; 4];
+                    bytes.copy_from_slice(&octets[i * 4..i * 4 + 4]);
+                    *one = u32::from_ne_bytes(bytes);
+                }
+                raw.addr.in6 = in6;
+            }
+            // only the 6 bytes of a mac, as in `mac()`
+            AFFamily::LINK => unsafe { raw.addr.mac[..6].copy_from_slice(&self.address.mac[..6]) },
+            AFFamily::UNSPEC => {}
+        }
+
+        raw
+    }
+
+    /// Returns the ip address, `None` for mac or unspecified addresses
+    pub fn ip(&self) -> Option<net::IpAddr> {
+        match self.family {
+            AFFamily::INET => Some(net::IpAddr::V4(self.address.inet4)),
+            AFFamily::INET6 => Some(net::IpAddr::V6(self.address.inet6)),
+            _ => None,
+        }
+    }
+
+    /// Returns the socket address with given port, `None` if not an ip address
+    pub fn socket_addr(&self, port: u16) -> Option<net::SocketAddr> {
+        self.ip().map(|ip| net::SocketAddr::new(ip, port))
+    }
+
+    /// Returns the mac address, `None` if not a link address
+    pub fn mac(&self) -> Option<MacAddr> {
+        match self.family {
+            AFFamily::LINK => {
+                let mut mac = [0u8; 6];
+                mac.copy_from_slice(&self.address.mac[..6]);
+                Some(MacAddr(mac))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl From<net::IpAddr> for NetAddress {
+    fn from(ip: net::IpAddr) -> Self {
+        let (family, inet4, inet6) = match ip {
+            net::IpAddr::V4(ip) => (AFFamily::INET, ip, ip.to_ipv6_mapped()),
+            net::IpAddr::V6(ip) => (AFFamily::INET6, net::Ipv4Addr::UNSPECIFIED, ip),
+        };
+
+        NetAddress {
+            family,
+            address: Address {
+                inet4,
+                inet6,
+                mac: [0; 8],
+            },
+        }
+    }
+}
+
+impl From<MacAddr> for NetAddress {
+    fn from(mac: MacAddr) -> Self {
+        let mut raw = [0u8; 8];
+        raw[..6].copy_from_slice(&mac.0);
+
+        NetAddress {
+            family: AFFamily::LINK,
+            address: Address {
+                inet4: net::Ipv4Addr::UNSPECIFIED,
+                inet6: net::Ipv6Addr::UNSPECIFIED,
+                mac: raw,
+            },
+        }
+    }
+}
+
+// C: sigar_net_address_to_string
+/// Formats like sigar does, e.g. `10.0.0.1`, `fe80::1` or `02:FC:00:00:00:05`
+impl fmt::Display for NetAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let mut raw = self.to_raw();
+        let mut buf = [0 as c_char; SIGAR_INET6_ADDRSTRLEN as usize + 1];
+
+        // the sigar handle is never read when formatting
+        let res =
+            unsafe { sigar_net_address_to_string(ptr::null_mut(), &mut raw, buf.as_mut_ptr()) };
+        if res != SIGAR_CODE_OK {
+            return Err(fmt::Error);
+        }
+
+        f.write_str(&chars_to_string(&buf[..]))
+    }
+}
+
+// C: sigar_net_address_equals
+impl PartialEq for NetAddress {
+    fn eq(&self, other: &Self) -> bool {
+        if self.family == AFFamily::UNSPEC || other.family == AFFamily::UNSPEC {
+            return self.family == other.family;
+        }
+
+        let (mut a, mut b) = (self.to_raw(), other.to_raw());
+        unsafe { sigar_net_address_equals(&mut a, &mut b) == SIGAR_CODE_OK }
+    }
+}
+
+impl Eq for NetAddress {}
+
+// C: sigar_net_address_hash
+impl Hash for NetAddress {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut raw = self.to_raw();
+        self.family.hash(state);
+        unsafe { sigar_net_address_hash(&mut raw) }.hash(state);
+    }
 }
 
 #[derive(Debug)]
@@ -141,19 +286,169 @@ impl Route {
     }
 }
 
+/// Returns route list
 pub fn route_list() -> SigarResult<Vec<Route>> {
-    ffi_wrap_destroy!(
-        sigar_net_route_list_get,
-        sigar_net_route_list_destroy,
-        sigar_net_route_list_t,
-        (|list_t: &sigar_net_route_list_t| ffi_extract_list!(
-            list_t,
-            (|one: &sigar_net_route_t| Route::from_raw(one))
-        ))
-    )
+    Sigar::new()?.net_route_list()
 }
 
 // C: sigar_net_interface_config_get
+/// Interface flags, like `IFF_*` on linux
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct InterfaceFlags(u64);
+
+macro_rules! interface_flags {
+    ($($(#[$attr:meta])* $name:ident = $raw:ident, $is:ident;)+) => {
+        impl InterfaceFlags {
+            $(
+            $(#[$attr])*
+            pub const $name: InterfaceFlags = InterfaceFlags($raw as u64);
+            )+
+
+            const ALL: &'static [(&'static str, InterfaceFlags)] = &[
+                $((stringify!($name), InterfaceFlags::$name),)+
+            ];
+
+            $(
+            #[doc = concat!("Returns true if `", stringify!($name), "` is set")]
+            pub fn $is(self) -> bool {
+                self.contains(InterfaceFlags::$name)
+            }
+            )+
+        }
+    };
+}
+
+interface_flags! {
+    UP = SIGAR_IFF_UP, is_up;
+    BROADCAST = SIGAR_IFF_BROADCAST, is_broadcast;
+    DEBUG = SIGAR_IFF_DEBUG, is_debug;
+    LOOPBACK = SIGAR_IFF_LOOPBACK, is_loopback;
+    POINTOPOINT = SIGAR_IFF_POINTOPOINT, is_point_to_point;
+    NOTRAILERS = SIGAR_IFF_NOTRAILERS, is_no_trailers;
+    /// resources allocated, the link may still be down
+    RUNNING = SIGAR_IFF_RUNNING, is_running;
+    NOARP = SIGAR_IFF_NOARP, is_no_arp;
+    PROMISC = SIGAR_IFF_PROMISC, is_promisc;
+    ALLMULTI = SIGAR_IFF_ALLMULTI, is_all_multi;
+    MULTICAST = SIGAR_IFF_MULTICAST, is_multicast;
+    /// member of a bond
+    SLAVE = SIGAR_IFF_SLAVE, is_slave;
+    /// master of a bond
+    MASTER = SIGAR_IFF_MASTER, is_master;
+    DYNAMIC = SIGAR_IFF_DYNAMIC, is_dynamic;
+}
+
+impl InterfaceFlags {
+    /// Returns flags from raw bits, unknown bits are kept
+    pub fn from_bits(bits: u64) -> Self {
+        InterfaceFlags(bits)
+    }
+
+    /// Returns raw bits
+    pub fn bits(self) -> u64 {
+        self.0
+    }
+
+    /// Returns true if no flag is set
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns true if all flags in `other` are set
+    pub fn contains(self, other: InterfaceFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl ops::BitOr for InterfaceFlags {
+    type Output = InterfaceFlags;
+
+    fn bitor(self, other: InterfaceFlags) -> InterfaceFlags {
+        InterfaceFlags(self.0 | other.0)
+    }
+}
+
+impl ops::BitAnd for InterfaceFlags {
+    type Output = InterfaceFlags;
+
+    fn bitand(self, other: InterfaceFlags) -> InterfaceFlags {
+        InterfaceFlags(self.0 & other.0)
+    }
+}
+
+impl fmt::Debug for InterfaceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let names: Vec<&str> = InterfaceFlags::ALL
+            .iter()
+            .filter(|&&(_, flag)| self.contains(flag))
+            .map(|&(name, _)| name)
+            .collect();
+
+        write!(f, "InterfaceFlags({})", names.join(" | "))
+    }
+}
+
+// C: sigar_net_interface_flags_to_string
+/// Formats like ifconfig does, e.g. `UP BROADCAST RUNNING MULTICAST`
+impl fmt::Display for InterfaceFlags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        // enough for every flag name followed by a space
+        let mut buf = [0 as c_char; 256];
+        unsafe { sigar_net_interface_flags_to_string(self.0, buf.as_mut_ptr()) };
+        f.write_str(chars_to_string(&buf[..]).trim_end())
+    }
+}
+
+/// Ipv6 address scopes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Ipv6Scope {
+    Global,
+    /// loopback
+    Host,
+    Link,
+    Site,
+    /// ipv4 compatible
+    Compat,
+    Unknown(i32),
+}
+
+impl Ipv6Scope {
+    #[allow(non_upper_case_globals)]
+    fn from_raw(raw: c_int) -> Self {
+        match raw as u32 {
+            SIGAR_IPV6_ADDR_ANY => Ipv6Scope::Global,
+            SIGAR_IPV6_ADDR_LOOPBACK => Ipv6Scope::Host,
+            SIGAR_IPV6_ADDR_LINKLOCAL => Ipv6Scope::Link,
+            SIGAR_IPV6_ADDR_SITELOCAL => Ipv6Scope::Site,
+            SIGAR_IPV6_ADDR_COMPATv4 => Ipv6Scope::Compat,
+            _ => Ipv6Scope::Unknown(raw),
+        }
+    }
+
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            Ipv6Scope::Global => SIGAR_IPV6_ADDR_ANY,
+            Ipv6Scope::Host => SIGAR_IPV6_ADDR_LOOPBACK,
+            Ipv6Scope::Link => SIGAR_IPV6_ADDR_LINKLOCAL,
+            Ipv6Scope::Site => SIGAR_IPV6_ADDR_SITELOCAL,
+            Ipv6Scope::Compat => SIGAR_IPV6_ADDR_COMPATv4,
+            Ipv6Scope::Unknown(raw) => return raw,
+        };
+
+        raw as c_int
+    }
+}
+
+// C: sigar_net_scope_to_string
+/// Formats like sigar does, e.g. `Global` or `Link`
+impl fmt::Display for Ipv6Scope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(&ptr_to_string(unsafe {
+            sigar_net_scope_to_string(self.to_raw())
+        }))
+    }
+}
+
 #[derive(Debug)]
 pub struct InterfaceConfig {
     pub name: Vec<u8>,
@@ -166,8 +461,8 @@ pub struct InterfaceConfig {
     pub netmask: NetAddress,
     pub address6: NetAddress,
     pub prefix6_length: i32,
-    pub scope6: i32,
-    pub flags: u64,
+    pub scope6: Ipv6Scope,
+    pub flags: InterfaceFlags,
     pub mtu: u64,
     pub metric: u64,
     pub tx_queue_len: i32,
@@ -179,11 +474,11 @@ impl InterfaceConfig {
             InterfaceConfig,
             raw,
             prefix6_length,
-            scope6,
-            flags,
             mtu,
             metric,
             tx_queue_len,
+            (scope6: Ipv6Scope::from_raw(raw.scope6)),
+            (flags: InterfaceFlags::from_bits(raw.flags)),
             (name: chars_to_bytes(&raw.name[..])),
             (type_: chars_to_bytes(&raw.type_[..])),
             (description: chars_to_bytes(&raw.description[..])),
@@ -199,25 +494,13 @@ impl InterfaceConfig {
 
 /// Returns interface config for given name
 pub fn interface_config(name: &str) -> SigarResult<InterfaceConfig> {
-    let name_ptr = CString::new(name).map_err(|e| Error::CString(e.description().to_string()))?;
-    let raw = ffi_wrap!(
-        sigar_net_interface_config_get,
-        (name_ptr.as_ptr()),
-        sigar_net_interface_config_t
-    )?;
-
-    Ok(InterfaceConfig::from_raw(&raw))
+    Sigar::new()?.net_interface_config(name)
 }
 
 // C: sigar_net_interface_config_primary_get
 /// Returns config for primary interface
 pub fn interface_config_primary() -> SigarResult<InterfaceConfig> {
-    let raw = ffi_wrap!(
-        sigar_net_interface_config_primary_get,
-        sigar_net_interface_config_t
-    )?;
-
-    Ok(InterfaceConfig::from_raw(&raw))
+    Sigar::new()?.net_interface_config_primary()
 }
 
 // C: sigar_net_interface_stat_get
@@ -239,49 +522,271 @@ pub struct InterfaceStat {
     pub speed: u64,
 }
 
-/// Returns interface stat for give name
+/// Returns interface stat for given name
 pub fn interface_stat(name: &str) -> SigarResult<InterfaceStat> {
-    let name_ptr = CString::new(name).map_err(|e| Error::CString(e.description().to_string()))?;
-
-    let raw = ffi_wrap!(
-        sigar_net_interface_stat_get,
-        (name_ptr.as_ptr()),
-        sigar_net_interface_stat_t
-    )?;
-
-    Ok(value_convert!(
-        InterfaceStat,
-        raw,
-        rx_packets,
-        rx_bytes,
-        rx_errors,
-        rx_dropped,
-        rx_overruns,
-        rx_frame,
-        tx_packets,
-        tx_bytes,
-        tx_errors,
-        tx_dropped,
-        tx_overruns,
-        tx_collisions,
-        tx_carrier,
-        speed,
-    ))
+    Sigar::new()?.net_interface_stat(name)
 }
 
 // C: sigar_net_interface_list_get
 
 /// Returns interface names
 pub fn interface_list() -> SigarResult<Vec<CString>> {
-    ffi_wrap_destroy!(
-        sigar_net_interface_list_get,
-        sigar_net_interface_list_destroy,
-        sigar_net_interface_list_t,
-        (|list_ptr: &sigar_net_interface_list_t| ffi_extract_list!(
-            list_ptr,
-            (|one: &*mut ::std::os::raw::c_char| CStr::from_ptr(*one).to_owned())
-        ))
-    )
+    Sigar::new()?.net_interface_list()
+}
+
+/// Flags of an interface address, like `IFA_F_*` on linux
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AddressFlags(u32);
+
+impl AddressFlags {
+    /// temporary (privacy extension) address
+    pub const TEMPORARY: AddressFlags = AddressFlags(0x01);
+    pub const NODAD: AddressFlags = AddressFlags(0x02);
+    pub const OPTIMISTIC: AddressFlags = AddressFlags(0x04);
+    /// duplicate address detection failed
+    pub const DADFAILED: AddressFlags = AddressFlags(0x08);
+    pub const HOMEADDRESS: AddressFlags = AddressFlags(0x10);
+    /// preferred lifetime expired, not used for new connections
+    pub const DEPRECATED: AddressFlags = AddressFlags(0x20);
+    /// duplicate address detection in progress
+    pub const TENTATIVE: AddressFlags = AddressFlags(0x40);
+    /// statically configured, not autoconfigured
+    pub const PERMANENT: AddressFlags = AddressFlags(0x80);
+
+    /// Returns flags from raw bits, unknown bits are kept
+    pub fn from_bits(bits: u32) -> Self {
+        AddressFlags(bits)
+    }
+
+    /// Returns the raw bits
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
+    /// Returns true if all flags in `other` are set
+    pub fn contains(self, other: AddressFlags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns true if `TEMPORARY` is set
+    pub fn is_temporary(self) -> bool {
+        self.contains(AddressFlags::TEMPORARY)
+    }
+
+    /// Returns true if `DEPRECATED` is set
+    pub fn is_deprecated(self) -> bool {
+        self.contains(AddressFlags::DEPRECATED)
+    }
+
+    /// Returns true if `TENTATIVE` is set
+    pub fn is_tentative(self) -> bool {
+        self.contains(AddressFlags::TENTATIVE)
+    }
+
+    /// Returns true if `PERMANENT` is set
+    pub fn is_permanent(self) -> bool {
+        self.contains(AddressFlags::PERMANENT)
+    }
+}
+
+impl ops::BitOr for AddressFlags {
+    type Output = AddressFlags;
+
+    fn bitor(self, other: AddressFlags) -> AddressFlags {
+        AddressFlags(self.0 | other.0)
+    }
+}
+
+/// One ipv4 or ipv6 address configured on an interface
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InterfaceAddress {
+    /// interface name, e.g. `eth0`
+    pub name: String,
+    /// name the address is configured under, e.g. `eth0:1` for a labeled ipv4 alias
+    pub label: String,
+    pub address: NetAddress,
+    pub prefix_length: u32,
+    /// `None` for ipv4 addresses
+    pub scope: Option<Ipv6Scope>,
+    /// only known for ipv6 addresses on linux, empty otherwise
+    pub flags: AddressFlags,
+}
+
+/// Returns every ipv4 and ipv6 address of all interfaces
+///
+/// Unlike [`InterfaceConfig`], which holds a single address of each family,
+/// this includes secondary ipv4 addresses, labeled or not, and all ipv6 addresses
+/// (link-local, global, temporary), see [`Sigar::net_interface_address_list`].
+///
+/// [`InterfaceConfig`]: struct.InterfaceConfig.html
+/// [`Sigar::net_interface_address_list`]: ../struct.Sigar.html#method.net_interface_address_list
+pub fn interface_address_list() -> SigarResult<Vec<InterfaceAddress>> {
+    Sigar::new()?.net_interface_address_list()
+}
+
+/// Returns every ipv4 and ipv6 address of given interface
+pub fn interface_addresses(name: &str) -> SigarResult<Vec<InterfaceAddress>> {
+    Sigar::new()?.net_interface_addresses(name)
+}
+
+/// Returns the interface name of an alias label, `eth0` for `eth0:1`
+fn label_interface(label: &str) -> &str {
+    label.split(':').next().unwrap_or(label)
+}
+
+/// Adds `extra` addresses not in `addresses` yet, an address already there
+/// takes the flags and label of its `extra` counterpart
+#[cfg(unix)]
+fn merge_addresses(addresses: &mut Vec<InterfaceAddress>, extra: Vec<InterfaceAddress>) {
+    for one in extra {
+        let known = addresses
+            .iter_mut()
+            .find(|known| known.name == one.name && known.address == one.address);
+        match known {
+            Some(known) => {
+                known.label = one.label;
+                known.flags = one.flags;
+            }
+            None => addresses.push(one),
+        }
+    }
+}
+
+/// Returns the prefix length of a netmask, `24` for `255.255.255.0`
+fn prefix_length(netmask: net::IpAddr) -> u32 {
+    match netmask {
+        net::IpAddr::V4(netmask) => u32::from(netmask).count_ones(),
+        net::IpAddr::V6(netmask) => u128::from(netmask).count_ones(),
+    }
+}
+
+/// Returns the scope of an ipv6 address, as the kernel classifies it
+#[cfg(unix)]
+fn ipv6_scope(ip: &net::Ipv6Addr) -> Ipv6Scope {
+    let segments = ip.segments();
+    if ip.is_loopback() {
+        Ipv6Scope::Host
+    } else if segments[0] & 0xffc0 == 0xfe80 {
+        Ipv6Scope::Link
+    } else if segments[0] & 0xffc0 == 0xfec0 {
+        Ipv6Scope::Site
+    } else if segments[..6] == [0; 6] && !ip.is_unspecified() {
+        Ipv6Scope::Compat
+    } else {
+        Ipv6Scope::Global
+    }
+}
+
+/// Reads the ip address of `family` in `addr`, netmasks on bsd leave `sa_family` unset
+#[cfg(unix)]
+unsafe fn sockaddr_ip(addr: *const libc::sockaddr, family: c_int) -> Option<net::IpAddr> {
+    if addr.is_null() {
+        return None;
+    }
+
+    match family {
+        libc::AF_INET => {
+            let sin = &*(addr as *const libc::sockaddr_in);
+            Some(net::IpAddr::from(sin.sin_addr.s_addr.to_ne_bytes()))
+        }
+        libc::AF_INET6 => {
+            let sin6 = &*(addr as *const libc::sockaddr_in6);
+            Some(net::IpAddr::from(sin6.sin6_addr.s6_addr))
+        }
+        _ => None,
+    }
+}
+
+/// Reads all addresses of `family` with getifaddrs
+///
+/// sigar reads ipv4 addresses with SIOCGIFCONF and SIOCGIFADDR, which only see
+/// the primary address of each interface and labeled aliases.
+#[cfg(unix)]
+fn if_addrs(family: c_int) -> SigarResult<Vec<InterfaceAddress>> {
+    let mut head: *mut libc::ifaddrs = ptr::null_mut();
+    if unsafe { libc::getifaddrs(&mut head) } != 0 {
+        return Err(Error::from(::std::io::Error::last_os_error()).with_op("getifaddrs"));
+    }
+
+    let mut addresses = Vec::new();
+    let mut cur = head;
+    while !cur.is_null() {
+        let one = unsafe { &*cur };
+        cur = one.ifa_next;
+
+        if one.ifa_addr.is_null() || c_int::from(unsafe { (*one.ifa_addr).sa_family }) != family {
+            continue;
+        }
+
+        let ip = match unsafe { sockaddr_ip(one.ifa_addr, family) } {
+            Some(ip) => ip,
+            None => continue,
+        };
+        let label = ptr_to_string(one.ifa_name);
+
+        addresses.push(InterfaceAddress {
+            name: label_interface(&label).to_string(),
+            prefix_length: unsafe { sockaddr_ip(one.ifa_netmask, family) }.map_or(0, prefix_length),
+            scope: match ip {
+                net::IpAddr::V4(_) => None,
+                net::IpAddr::V6(ref ip) => Some(ipv6_scope(ip)),
+            },
+            address: NetAddress::from(ip),
+            flags: AddressFlags::default(),
+            label,
+        });
+    }
+
+    unsafe { libc::freeifaddrs(head) };
+
+    Ok(addresses)
+}
+
+/// Reads all ipv6 addresses along with their flags from /proc/net/if_inet6
+#[cfg(target_os = "linux")]
+fn if_inet6() -> SigarResult<Vec<InterfaceAddress>> {
+    let content = match ::std::fs::read_to_string("/proc/net/if_inet6") {
+        Ok(content) => content,
+        // ipv6 disabled
+        Err(ref e) if e.kind() == ::std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(Error::from(e)
+                .with_op("if_inet6")
+                .with_name("/proc/net/if_inet6"))
+        }
+    };
+
+    Ok(content.lines().filter_map(parse_if_inet6).collect())
+}
+
+/// Parses a line of /proc/net/if_inet6, e.g.
+/// `fe800000000000000000000000000001 02 40 20 80     eth0`
+#[cfg(target_os = "linux")]
+fn parse_if_inet6(line: &str) -> Option<InterfaceAddress> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 6 {
+        return None;
+    }
+
+    let is_hex = |field: &str| !field.is_empty() && field.bytes().all(|b| b.is_ascii_hexdigit());
+    if fields[0].len() != 32 || !fields[..5].iter().all(|field| is_hex(field)) {
+        return None;
+    }
+
+    let mut octets = [0u8; 16];
+    for (i, one) in octets.iter_mut().enumerate() {
+        *one = u8::from_str_radix(&fields[0][i * 2..i * 2 + 2], 16).ok()?;
+    }
+    let hex = |field: &str| u32::from_str_radix(field, 16).ok();
+
+    Some(InterfaceAddress {
+        name: fields[5].to_string(),
+        label: fields[5].to_string(),
+        address: NetAddress::from(net::IpAddr::from(octets)),
+        prefix_length: hex(fields[2])?,
+        scope: Some(Ipv6Scope::from_raw(hex(fields[3])? as c_int)),
+        flags: AddressFlags::from_bits(hex(fields[4])?),
+    })
 }
 
 // C: sigar_net_connection_list_get
@@ -301,7 +806,7 @@ pub struct Conn {
 }
 
 #[allow(non_camel_case_types)]
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ConnType {
     TCP,
     UDP,
@@ -320,6 +825,18 @@ impl ConnType {
             _ => ConnType::UNKNOWN,
         }
     }
+
+    fn to_raw(self) -> c_int {
+        let raw = match self {
+            ConnType::TCP => SIGAR_NETCONN_TCP,
+            ConnType::UDP => SIGAR_NETCONN_UDP,
+            ConnType::RAW => SIGAR_NETCONN_RAW,
+            ConnType::UNIX => SIGAR_NETCONN_UNIX,
+            ConnType::UNKNOWN => 0,
+        };
+
+        raw as c_int
+    }
 }
 
 #[allow(non_camel_case_types)]
@@ -380,6 +897,16 @@ impl Conn {
             (type_: ConnType::from_raw(raw.type_)),
         )
     }
+
+    /// Returns the local address and port, `None` for non ip connections
+    pub fn local_socket_addr(&self) -> Option<net::SocketAddr> {
+        self.local_address.socket_addr(self.local_port as u16)
+    }
+
+    /// Returns the remote address and port, `None` for non ip connections
+    pub fn remote_socket_addr(&self) -> Option<net::SocketAddr> {
+        self.remote_address.socket_addr(self.remote_port as u16)
+    }
 }
 
 type Flag = u32;
@@ -392,17 +919,106 @@ pub const FLAG_NETCONN_UNIX: Flag = SIGAR_NETCONN_UNIX;
 
 /// Returns all connections for given flags
 pub fn connection_list(flags: Flag) -> SigarResult<Vec<Conn>> {
-    ffi_wrap_destroy!(
-        (|ptr: *mut sigar_t, connlist: *mut sigar_net_connection_list_t| {
-            sigar_net_connection_list_get(ptr, connlist, flags as c_int)
-        }),
-        sigar_net_connection_list_destroy,
-        sigar_net_connection_list_t,
-        (|list_ptr: &sigar_net_connection_list_t| ffi_extract_list!(
-            list_ptr,
-            (|one: &sigar_net_connection_t| Conn::from_raw(one))
-        ))
-    )
+    Sigar::new()?.net_connection_list(flags)
+}
+
+// C: sigar_net_connection_walk
+/// Calls `f` with each connection for given flags, stopping as soon as it
+/// returns false, without collecting them into a list first
+pub fn connection_walk<F>(flags: Flag, f: F) -> SigarResult<()>
+where
+    F: FnMut(Conn) -> bool,
+{
+    Sigar::new()?.net_connection_walk(flags, f)
+}
+
+struct Walk<F> {
+    f: F,
+    stopped: bool,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+/// Any non ok code stops sigar walking the current table
+const WALK_STOP: c_int = 1;
+
+unsafe extern "C" fn walk_connection<F>(
+    walker: *mut sigar_net_connection_walker_t,
+    conn: *mut sigar_net_connection_t,
+) -> c_int
+where
+    F: FnMut(Conn) -> bool,
+{
+    let walk = &mut *((*walker).data as *mut Walk<F>);
+
+    // sigar moves on to the next table (tcp6, udp ...) after a stop,
+    // so keep stopping until the walk returns
+    if walk.stopped {
+        return WALK_STOP;
+    }
+
+    let conn = Conn::from_raw(&*conn);
+    let f = &mut walk.f;
+    match panic::catch_unwind(AssertUnwindSafe(|| f(conn))) {
+        Ok(true) => SIGAR_CODE_OK,
+        Ok(false) => {
+            walk.stopped = true;
+            WALK_STOP
+        }
+        Err(payload) => {
+            walk.panic = Some(payload);
+            walk.stopped = true;
+            WALK_STOP
+        }
+    }
+}
+
+// C: sigar_net_services_name_get
+/// Returns the service name of `port` from the services file
+/// (`/etc/services` on unix), e.g. `postgresql` for tcp port 5432
+///
+/// `None` if unknown, or for other types than tcp and udp.
+/// The file is read on every call, use [`ServiceNames`] for repeated lookups.
+///
+/// [`ServiceNames`]: struct.ServiceNames.html
+pub fn service_name(type_: ConnType, port: u64) -> SigarResult<Option<String>> {
+    Ok(Sigar::new()?.net_service_name(type_, port))
+}
+
+/// Caches service names of ports, reading the services file once
+#[derive(Debug)]
+pub struct ServiceNames {
+    sigar: Sigar,
+    names: HashMap<(ConnType, u64), Option<String>>,
+}
+
+impl ServiceNames {
+    /// Returns an empty cache
+    pub fn new() -> SigarResult<Self> {
+        Ok(ServiceNames {
+            sigar: Sigar::new()?,
+            names: HashMap::new(),
+        })
+    }
+
+    /// Returns the service name of `port`, `None` if unknown
+    pub fn get(&mut self, type_: ConnType, port: u64) -> Option<&str> {
+        let sigar = &self.sigar;
+        self.names
+            .entry((type_, port))
+            .or_insert_with(|| sigar.net_service_name(type_, port))
+            .as_ref()
+            .map(String::as_str)
+    }
+
+    /// Returns the service name of the local port of `conn`
+    pub fn local(&mut self, conn: &Conn) -> Option<&str> {
+        self.get(conn.type_, conn.local_port)
+    }
+
+    /// Returns the service name of the remote port of `conn`
+    pub fn remote(&mut self, conn: &Conn) -> Option<&str> {
+        self.get(conn.type_, conn.remote_port)
+    }
 }
 
 // C: sigar_net_stat_get
@@ -417,13 +1033,6 @@ pub struct Stat {
 
 impl Stat {
     fn from_raw(raw: &sigar_net_stat_t) -> Self {
-        let mut tcp_states: [i32; 14usize] = [0; 14usize];
-        let mut i = 0usize;
-        while i < 14 {
-            tcp_states[i] = raw.tcp_states[i] as i32;
-            i += 1;
-        }
-
         value_convert!(
             Stat,
             raw,
@@ -431,45 +1040,774 @@ impl Stat {
             tcp_outbound_total,
             all_inbound_total,
             all_outbound_total,
-            (tcp_states: tcp_states),
+            (tcp_states: raw.tcp_states),
         )
     }
 }
 
 /// Returns connection stat summary for given flags
 pub fn stat_get(flags: Flag) -> SigarResult<Stat> {
-    let raw = ffi_wrap!(
-        (|sigar: *mut sigar_t, netstat: *mut sigar_net_stat_t| sigar_net_stat_get(
-            sigar,
-            netstat,
-            flags as c_int
-        )),
-        sigar_net_stat_t
-    )?;
+    Sigar::new()?.net_stat(flags)
+}
 
-    Ok(Stat::from_raw(&raw))
+// C: sigar_net_stat_port_get
+/// Returns tcp connection stat summary for a single service
+///
+/// Connections with `port` as local port count as inbound, those with it as
+/// remote port as outbound. The `tcp_*_total` fields only count connections
+/// which also match `address`, the `all_*_total` fields count any address.
+pub fn stat_port(flags: Flag, address: net::IpAddr, port: u64) -> SigarResult<Stat> {
+    Sigar::new()?.net_stat_port(flags, address, port)
 }
 
 // C: sigar_net_listen_address_get
 /// Returns the bind address for a given port
 pub fn listen_address_get(port: u64) -> SigarResult<NetAddress> {
-    let raw = ffi_wrap!(
-        sigar_net_listen_address_get,
-        (port as c_ulong),
-        sigar_net_address_t
-    )?;
+    Sigar::new()?.net_listen_address(port)
+}
+
+// C: sigar_tcp_get
+/// Host wide tcp counters, from the tcp mib
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Tcp {
+    pub active_opens: u64,
+    pub passive_opens: u64,
+    pub attempt_fails: u64,
+    pub estab_resets: u64,
+    /// connections currently established, a gauge
+    pub curr_estab: u64,
+    pub in_segs: u64,
+    pub out_segs: u64,
+    pub retrans_segs: u64,
+    pub in_errs: u64,
+    pub out_rsts: u64,
+}
+
+impl Tcp {
+    fn from_raw(raw: &sigar_tcp_t) -> Self {
+        value_convert!(
+            Tcp,
+            raw,
+            active_opens,
+            passive_opens,
+            attempt_fails,
+            estab_resets,
+            curr_estab,
+            in_segs,
+            out_segs,
+            retrans_segs,
+            in_errs,
+            out_rsts
+        )
+    }
+
+    /// Returns retransmitted segments / sent segments since `prev`,
+    /// from 0.0 to 1.0, or 0.0 if nothing was sent
+    ///
+    /// A counter lower than in `prev` (counters reset) counts from zero.
+    pub fn retrans_rate(&self, prev: &Tcp) -> f64 {
+        let out_segs = counter_delta(prev.out_segs, self.out_segs);
+        if out_segs == 0 {
+            return 0.0;
+        }
+
+        let retrans_segs = counter_delta(prev.retrans_segs, self.retrans_segs);
+        (retrans_segs as f64 / out_segs as f64).min(1.0)
+    }
+}
+
+/// Returns host wide tcp counters
+pub fn tcp() -> SigarResult<Tcp> {
+    Sigar::new()?.net_tcp()
+}
+
+// C: sigar_arp_list_get
+/// An entry of the arp (ipv4 neighbour) table
+#[derive(Debug)]
+pub struct Arp {
+    pub ifname: String,
+    /// hardware type, e.g. `ether`
+    pub type_: String,
+    pub hwaddr: NetAddress,
+    pub address: NetAddress,
+    /// os arp flags, e.g. `ATF_COM` (0x2) for a completed entry on linux
+    pub flags: u64,
+}
+
+impl Arp {
+    fn from_raw(raw: &sigar_arp_t) -> Self {
+        value_convert!(
+            Arp,
+            raw,
+            flags,
+            (ifname: chars_to_string(&raw.ifname[..])),
+            (type_: chars_to_string(&raw.type_[..])),
+            (hwaddr: NetAddress::from_raw(&raw.hwaddr)),
+            (address: NetAddress::from_raw(&raw.address)),
+        )
+    }
+}
+
+/// Returns the arp table
+pub fn arp_list() -> SigarResult<Vec<Arp>> {
+    Sigar::new()?.net_arp_list()
+}
+
+impl Sigar {
+    /// Returns net info
+    pub fn net_info(&self) -> SigarResult<Info> {
+        let raw = ffi_wrap!(self, sigar_net_info_get, sigar_net_info_t)?;
+
+        Ok(Info {
+            default_gateway: chars_to_bytes(&raw.default_gateway[..]),
+            default_gateway_interface: chars_to_bytes(&raw.default_gateway_interface[..]),
+            host_name: chars_to_bytes(&raw.host_name[..]),
+            domain_name: chars_to_bytes(&raw.domain_name[..]),
+            primary_dns: chars_to_bytes(&raw.primary_dns[..]),
+            secondary_dns: chars_to_bytes(&raw.secondary_dns[..]),
+        })
+    }
+
+    /// Returns route list
+    pub fn net_route_list(&self) -> SigarResult<Vec<Route>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_net_route_list_get,
+            sigar_net_route_list_destroy,
+            sigar_net_route_list_t,
+            (|list_t: &sigar_net_route_list_t| ffi_extract_list!(
+                list_t,
+                (|one: &sigar_net_route_t| Route::from_raw(one))
+            ))
+        )
+    }
+
+    /// Returns interface config for given name
+    pub fn net_interface_config(&self, name: &str) -> SigarResult<InterfaceConfig> {
+        let name_ptr = CString::new(name)?;
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_interface_config_get,
+            (name_ptr.as_ptr()),
+            sigar_net_interface_config_t
+        )
+        .map_err(|e| e.with_name(name))?;
+
+        Ok(InterfaceConfig::from_raw(&raw))
+    }
+
+    /// Returns config for primary interface
+    pub fn net_interface_config_primary(&self) -> SigarResult<InterfaceConfig> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_interface_config_primary_get,
+            sigar_net_interface_config_t
+        )?;
+
+        Ok(InterfaceConfig::from_raw(&raw))
+    }
+
+    /// Returns interface stat for given name
+    pub fn net_interface_stat(&self, name: &str) -> SigarResult<InterfaceStat> {
+        let name_ptr = CString::new(name)?;
+
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_interface_stat_get,
+            (name_ptr.as_ptr()),
+            sigar_net_interface_stat_t
+        )
+        .map_err(|e| e.with_name(name))?;
+
+        Ok(value_convert!(
+            InterfaceStat,
+            raw,
+            rx_packets,
+            rx_bytes,
+            rx_errors,
+            rx_dropped,
+            rx_overruns,
+            rx_frame,
+            tx_packets,
+            tx_bytes,
+            tx_errors,
+            tx_dropped,
+            tx_overruns,
+            tx_collisions,
+            tx_carrier,
+            speed,
+        ))
+    }
+
+    /// Returns interface names
+    pub fn net_interface_list(&self) -> SigarResult<Vec<CString>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_net_interface_list_get,
+            sigar_net_interface_list_destroy,
+            sigar_net_interface_list_t,
+            (|list_ptr: &sigar_net_interface_list_t| ffi_extract_list!(
+                list_ptr,
+                (|one: &*mut ::std::os::raw::c_char| CStr::from_ptr(*one).to_owned())
+            ))
+        )
+    }
+
+    /// Returns every ipv4 and ipv6 address of all interfaces
+    ///
+    /// sigar reports the primary address of each family per interface.
+    /// On unix the other addresses are added with getifaddrs, on linux ipv6
+    /// addresses and their flags are read from /proc/net/if_inet6.
+    /// On windows only the addresses sigar reports are returned,
+    /// a single ipv4 and ipv6 address per interface.
+    pub fn net_interface_address_list(&self) -> SigarResult<Vec<InterfaceAddress>> {
+        let mut addresses = self.net_interface_primary_addresses()?;
+
+        #[cfg(unix)]
+        merge_addresses(&mut addresses, if_addrs(libc::AF_INET)?);
+        #[cfg(target_os = "linux")]
+        merge_addresses(&mut addresses, if_inet6()?);
+        #[cfg(all(unix, not(target_os = "linux")))]
+        merge_addresses(&mut addresses, if_addrs(libc::AF_INET6)?);
+
+        // ipv4 first, keeping the order of each family
+        addresses.sort_by_key(|one| one.address.family == AFFamily::INET6);
+
+        Ok(addresses)
+    }
+
+    /// Returns every ipv4 and ipv6 address of given interface,
+    /// see [`net_interface_address_list`]
+    ///
+    /// [`net_interface_address_list`]: #method.net_interface_address_list
+    pub fn net_interface_addresses(&self, name: &str) -> SigarResult<Vec<InterfaceAddress>> {
+        let mut addresses = self.net_interface_address_list()?;
+        addresses.retain(|one| one.name == name);
+
+        Ok(addresses)
+    }
+
+    /// Takes the primary ipv4 and ipv6 address sigar reports of each interface
+    fn net_interface_primary_addresses(&self) -> SigarResult<Vec<InterfaceAddress>> {
+        let mut labels: Vec<String> = Vec::new();
+        let mut addresses = Vec::new();
+
+        for label in self.net_interface_list()? {
+            // SIOCGIFCONF lists an interface once per address
+            let label = label.to_string_lossy().into_owned();
+            if labels.contains(&label) {
+                continue;
+            }
+
+            let config = match self.net_interface_config(&label) {
+                Ok(config) => config,
+                // gone since listed
+                Err(ref e) if e.is_not_found() => continue,
+                Err(e) => return Err(e),
+            };
+
+            match config.address.ip() {
+                Some(ip @ net::IpAddr::V4(_)) if !ip.is_unspecified() => {
+                    addresses.push(InterfaceAddress {
+                        name: label_interface(&label).to_string(),
+                        label: label.clone(),
+                        address: config.address,
+                        prefix_length: config.netmask.ip().map_or(0, prefix_length),
+                        scope: None,
+                        flags: AddressFlags::default(),
+                    })
+                }
+                _ => {}
+            }
+
+            match config.address6.ip() {
+                Some(ip @ net::IpAddr::V6(_)) if !ip.is_unspecified() => {
+                    addresses.push(InterfaceAddress {
+                        name: label_interface(&label).to_string(),
+                        label: label.clone(),
+                        address: config.address6,
+                        prefix_length: config.prefix6_length as u32,
+                        scope: Some(config.scope6),
+                        flags: AddressFlags::default(),
+                    })
+                }
+                _ => {}
+            }
+
+            labels.push(label);
+        }
+
+        Ok(addresses)
+    }
+
+    /// Returns all connections for given flags
+    pub fn net_connection_list(&self, flags: Flag) -> SigarResult<Vec<Conn>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_net_connection_list_get => (|ptr: *mut sigar_t,
+                                               connlist: *mut sigar_net_connection_list_t| {
+                sigar_net_connection_list_get(ptr, connlist, flags as c_int)
+            }),
+            sigar_net_connection_list_destroy,
+            sigar_net_connection_list_t,
+            (|list_ptr: &sigar_net_connection_list_t| ffi_extract_list!(
+                list_ptr,
+                (|one: &sigar_net_connection_t| Conn::from_raw(one))
+            ))
+        )
+    }
 
-    Ok(NetAddress::from_raw(&raw))
+    /// Calls `f` with each connection for given flags, stopping as soon as it
+    /// returns false
+    ///
+    /// A panic in `f` stops the walk and is resumed once sigar returns.
+    pub fn net_connection_walk<F>(&self, flags: Flag, f: F) -> SigarResult<()>
+    where
+        F: FnMut(Conn) -> bool,
+    {
+        let mut walk = Walk {
+            f,
+            stopped: false,
+            panic: None,
+        };
+        let mut walker = sigar_net_connection_walker_t {
+            sigar: self.ptr,
+            flags: flags as c_int,
+            data: &mut walk as *mut Walk<F> as *mut c_void,
+            add_connection: Some(walk_connection::<F>),
+        };
+
+        let res = unsafe { sigar_net_connection_walk(&mut walker) };
+        if let Some(payload) = walk.panic {
+            panic::resume_unwind(payload);
+        }
+
+        if res != SIGAR_CODE_OK {
+            return Err(Error::new(self.ptr, res).with_op("sigar_net_connection_walk"));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the service name of `port`, `None` if unknown
+    pub fn net_service_name(&self, type_: ConnType, port: u64) -> Option<String> {
+        let name =
+            unsafe { sigar_net_services_name_get(self.ptr, type_.to_raw(), port as c_ulong) };
+        if name.is_null() {
+            return None;
+        }
+
+        Some(ptr_to_string(name))
+    }
+
+    /// Returns connection stat summary for given flags
+    pub fn net_stat(&self, flags: Flag) -> SigarResult<Stat> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_stat_get => (|sigar: *mut sigar_t, netstat: *mut sigar_net_stat_t| {
+                sigar_net_stat_get(sigar, netstat, flags as c_int)
+            }),
+            sigar_net_stat_t
+        )?;
+
+        Ok(Stat::from_raw(&raw))
+    }
+
+    /// Returns tcp connection stat summary for a single service
+    pub fn net_stat_port(&self, flags: Flag, address: net::IpAddr, port: u64) -> SigarResult<Stat> {
+        let mut raw_address = NetAddress::from(address).to_raw();
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_stat_port_get => (|sigar: *mut sigar_t, netstat: *mut sigar_net_stat_t| {
+                sigar_net_stat_port_get(
+                    sigar,
+                    netstat,
+                    flags as c_int,
+                    &mut raw_address,
+                    port as c_ulong,
+                )
+            }),
+            sigar_net_stat_t
+        )?;
+
+        Ok(Stat::from_raw(&raw))
+    }
+
+    /// Returns the bind address for a given port
+    pub fn net_listen_address(&self, port: u64) -> SigarResult<NetAddress> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_net_listen_address_get,
+            (port as c_ulong),
+            sigar_net_address_t
+        )?;
+
+        Ok(NetAddress::from_raw(&raw))
+    }
+
+    /// Returns the arp table
+    pub fn net_arp_list(&self) -> SigarResult<Vec<Arp>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_arp_list_get,
+            sigar_arp_list_destroy,
+            sigar_arp_list_t,
+            (|list_t: &sigar_arp_list_t| ffi_extract_list!(list_t, Arp::from_raw))
+        )
+    }
+
+    /// Returns host wide tcp counters
+    pub fn net_tcp(&self) -> SigarResult<Tcp> {
+        let raw = ffi_wrap!(self, sigar_tcp_get, sigar_tcp_t)?;
+        Ok(Tcp::from_raw(&raw))
+    }
 }
 
 // TODO:
-// C: sigar_net_connection_walk
-// C: sigar_net_stat_port_get
-// C: sigar_net_address_equals
-// C: sigar_net_address_to_string
-// C: sigar_net_scope_to_string
-// C: sigar_net_address_hash
 // C: sigar_net_connection_type_get
 // C: sigar_net_connection_state_get
-// C: sigar_net_interface_flags_to_string
-// C: sigar_net_services_name_get
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+
+    fn hash_of(address: &NetAddress) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        address.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn link(raw: [u8; 8]) -> NetAddress {
+        NetAddress {
+            family: AFFamily::LINK,
+            address: Address {
+                inet4: net::Ipv4Addr::UNSPECIFIED,
+                inet6: net::Ipv6Addr::UNSPECIFIED,
+                mac: raw,
+            },
+        }
+    }
+
+    #[test]
+    fn interface_flags_match_sigar() {
+        assert_eq!(InterfaceFlags::UP.bits(), SIGAR_IFF_UP as u64);
+        assert_eq!(InterfaceFlags::LOOPBACK.bits(), SIGAR_IFF_LOOPBACK as u64);
+        assert_eq!(InterfaceFlags::RUNNING.bits(), SIGAR_IFF_RUNNING as u64);
+        assert_eq!(InterfaceFlags::MULTICAST.bits(), SIGAR_IFF_MULTICAST as u64);
+        assert_eq!(InterfaceFlags::MASTER.bits(), SIGAR_IFF_MASTER as u64);
+    }
+
+    #[test]
+    fn interface_flags_predicates() {
+        let raw = SIGAR_IFF_UP | SIGAR_IFF_BROADCAST | SIGAR_IFF_RUNNING | SIGAR_IFF_MULTICAST;
+        let flags = InterfaceFlags::from_bits(raw as u64);
+
+        assert!(flags.is_up());
+        assert!(flags.is_broadcast());
+        assert!(flags.is_running());
+        assert!(flags.is_multicast());
+        assert!(!flags.is_loopback());
+        assert!(!flags.is_promisc());
+        assert!(!flags.is_empty());
+        assert!(InterfaceFlags::default().is_empty());
+
+        assert!(flags.contains(InterfaceFlags::UP | InterfaceFlags::RUNNING));
+        assert!(!flags.contains(InterfaceFlags::UP | InterfaceFlags::LOOPBACK));
+        assert_eq!(flags & InterfaceFlags::LOOPBACK, InterfaceFlags::default());
+        assert_eq!(
+            flags & (InterfaceFlags::UP | InterfaceFlags::LOOPBACK),
+            InterfaceFlags::UP
+        );
+    }
+
+    #[test]
+    fn interface_flags_format() {
+        let flags = InterfaceFlags::UP
+            | InterfaceFlags::BROADCAST
+            | InterfaceFlags::RUNNING
+            | InterfaceFlags::MULTICAST;
+        assert_eq!(flags.to_string(), "UP BROADCAST RUNNING MULTICAST");
+        assert_eq!(
+            format!("{:?}", flags),
+            "InterfaceFlags(UP | BROADCAST | RUNNING | MULTICAST)"
+        );
+
+        let loopback = InterfaceFlags::UP | InterfaceFlags::LOOPBACK | InterfaceFlags::RUNNING;
+        assert_eq!(loopback.to_string(), "UP LOOPBACK RUNNING");
+        assert_eq!(InterfaceFlags::default().to_string(), "[NO FLAGS]");
+    }
+
+    #[test]
+    fn ipv6_scope_from_raw() {
+        let scope = |raw: u32| Ipv6Scope::from_raw(raw as c_int);
+        assert_eq!(scope(SIGAR_IPV6_ADDR_ANY), Ipv6Scope::Global);
+        assert_eq!(scope(SIGAR_IPV6_ADDR_LOOPBACK), Ipv6Scope::Host);
+        assert_eq!(scope(SIGAR_IPV6_ADDR_LINKLOCAL), Ipv6Scope::Link);
+        assert_eq!(scope(SIGAR_IPV6_ADDR_SITELOCAL), Ipv6Scope::Site);
+        assert_eq!(scope(SIGAR_IPV6_ADDR_COMPATv4), Ipv6Scope::Compat);
+        assert_eq!(scope(0x0e), Ipv6Scope::Unknown(0x0e));
+
+        for &one in &[
+            Ipv6Scope::Global,
+            Ipv6Scope::Host,
+            Ipv6Scope::Link,
+            Ipv6Scope::Site,
+            Ipv6Scope::Compat,
+            Ipv6Scope::Unknown(0x0e),
+        ] {
+            assert_eq!(Ipv6Scope::from_raw(one.to_raw()), one);
+        }
+    }
+
+    #[test]
+    fn ipv6_scope_format() {
+        assert_eq!(Ipv6Scope::Global.to_string(), "Global");
+        assert_eq!(Ipv6Scope::Host.to_string(), "Host");
+        assert_eq!(Ipv6Scope::Link.to_string(), "Link");
+        assert_eq!(Ipv6Scope::Unknown(0x0e).to_string(), "Unknown");
+    }
+
+    #[test]
+    fn address_from_ipv4() {
+        let ip: net::IpAddr = "192.0.2.1".parse().unwrap();
+        let address = NetAddress::from(ip);
+        assert_eq!(address.family, AFFamily::INET);
+        assert_eq!(address.ip(), Some(ip));
+        assert_eq!(address.mac(), None);
+        assert_eq!(address.to_string(), "192.0.2.1");
+        assert_eq!(
+            address.socket_addr(80),
+            Some("192.0.2.1:80".parse().unwrap())
+        );
+        assert_eq!(NetAddress::from_raw(&address.to_raw()), address);
+    }
+
+    #[test]
+    fn address_from_ipv6() {
+        let ip: net::IpAddr = "fe80::fc:ff:fe00:1".parse().unwrap();
+        let address = NetAddress::from(ip);
+        assert_eq!(address.family, AFFamily::INET6);
+        assert_eq!(address.ip(), Some(ip));
+        assert_eq!(address.to_string(), "fe80::fc:ff:fe00:1");
+        assert_eq!(NetAddress::from_raw(&address.to_raw()).ip(), Some(ip));
+    }
+
+    #[test]
+    fn address_from_mac() {
+        let mac = MacAddr([0x02, 0xfc, 0, 0, 0, 0x05]);
+        let address = NetAddress::from(mac);
+        assert_eq!(address.family, AFFamily::LINK);
+        assert_eq!(address.mac(), Some(mac));
+        assert_eq!(address.ip(), None);
+        assert_eq!(address.to_string(), "02:FC:00:00:00:05");
+        assert_eq!(address.to_string(), mac.to_string());
+    }
+
+    #[test]
+    fn address_eq_and_hash() {
+        let a = NetAddress::from("10.0.0.1".parse::<net::IpAddr>().unwrap());
+        let b = NetAddress::from("10.0.0.1".parse::<net::IpAddr>().unwrap());
+        let c = NetAddress::from("10.0.0.2".parse::<net::IpAddr>().unwrap());
+        let v6 = NetAddress::from("::ffff:10.0.0.1".parse::<net::IpAddr>().unwrap());
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(a, c);
+        assert_ne!(a, v6);
+
+        let set: HashSet<NetAddress> = vec![a, b, c, v6].into_iter().collect();
+        assert_eq!(set.len(), 3);
+    }
+
+    #[test]
+    fn link_address_ignores_trailing_bytes() {
+        let a = link([2, 0xfc, 0, 0, 0, 5, 0, 0]);
+        let b = link([2, 0xfc, 0, 0, 0, 5, 0xaa, 0xbb]);
+        let c = link([2, 0xfc, 0, 0, 0, 6, 0, 0]);
+        assert_eq!(a.mac(), b.mac());
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_eq!(a.to_string(), b.to_string());
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn unspec_addresses() {
+        let unspec = NetAddress {
+            family: AFFamily::UNSPEC,
+            ..link([0; 8])
+        };
+        assert_eq!(unspec, unspec);
+        assert_ne!(unspec, link([0; 8]));
+        assert_eq!(unspec.ip(), None);
+    }
+
+    fn tcp(out_segs: u64, retrans_segs: u64) -> Tcp {
+        Tcp {
+            out_segs,
+            retrans_segs,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn retrans_rate() {
+        assert_eq!(tcp(1100, 15).retrans_rate(&tcp(100, 5)), 0.01);
+        assert_eq!(tcp(200, 5).retrans_rate(&tcp(100, 5)), 0.0);
+    }
+
+    #[test]
+    fn retrans_rate_nothing_sent() {
+        assert_eq!(tcp(100, 5).retrans_rate(&tcp(100, 5)), 0.0);
+        assert_eq!(tcp(100, 9).retrans_rate(&tcp(100, 5)), 0.0);
+    }
+
+    #[test]
+    fn retrans_rate_counter_reset() {
+        assert_eq!(tcp(400, 4).retrans_rate(&tcp(1000, 50)), 0.01);
+        assert_eq!(tcp(0, 0).retrans_rate(&tcp(1000, 50)), 0.0);
+    }
+
+    #[test]
+    fn retrans_rate_is_capped() {
+        assert_eq!(tcp(110, 50).retrans_rate(&tcp(100, 5)), 1.0);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn if_inet6_link_local() {
+        let one = parse_if_inet6("fe8000000000000000fc00fffe000001 04 40 20 80     eth0").unwrap();
+        assert_eq!(one.name, "eth0");
+        assert_eq!(one.label, "eth0");
+        assert_eq!(one.address.to_string(), "fe80::fc:ff:fe00:1");
+        assert_eq!(one.prefix_length, 64);
+        assert_eq!(one.scope, Some(Ipv6Scope::Link));
+        assert!(one.flags.is_permanent());
+        assert!(!one.flags.is_temporary());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn if_inet6_global_and_loopback() {
+        let one = parse_if_inet6("fd000000000000000000000000000002 04 40 00 82     eth0").unwrap();
+        assert_eq!(one.address.to_string(), "fd00::2");
+        assert_eq!(one.scope, Some(Ipv6Scope::Global));
+        assert_eq!(one.flags.bits(), 0x82);
+        assert!(one
+            .flags
+            .contains(AddressFlags::NODAD | AddressFlags::PERMANENT));
+
+        let lo = parse_if_inet6("00000000000000000000000000000001 01 80 10 80       lo").unwrap();
+        assert_eq!(lo.address.to_string(), "::1");
+        assert_eq!(lo.prefix_length, 128);
+        assert_eq!(lo.scope, Some(Ipv6Scope::Host));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn if_inet6_temporary_and_deprecated() {
+        let one = parse_if_inet6("20010db8000000001c5a3f2e9d4b7a61 03 40 00 21 wlp0s20f3").unwrap();
+        assert_eq!(one.name, "wlp0s20f3");
+        assert_eq!(one.address.to_string(), "2001:db8::1c5a:3f2e:9d4b:7a61");
+        assert!(one.flags.is_temporary());
+        assert!(one.flags.is_deprecated());
+        assert!(!one.flags.is_permanent());
+        assert!(!one.flags.is_tentative());
+
+        let tentative =
+            parse_if_inet6("20010db8000000000000000000000005 03 40 00 40 veth1a2b3c4d5e6").unwrap();
+        assert_eq!(tentative.name, "veth1a2b3c4d5e6");
+        assert!(tentative.flags.is_tentative());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn if_inet6_malformed() {
+        let bad = [
+            "",
+            "fe8000000000000000fc00fffe000001 04 40 20 80",
+            "fe8000000000000000fc00fffe000001 04 40 20 80 eth0 extra",
+            "fe8000000000000000fc00fffe0001 04 40 20 80     eth0",
+            "fe8000000000000000fc00fffe00000100 04 40 20 80 eth0",
+            "zz8000000000000000fc00fffe000001 04 40 20 80     eth0",
+            "+e8000000000000000fc00fffe000001 04 40 20 80     eth0",
+            "fe8000000000000000fc00fffe000001 04 4g 20 80     eth0",
+            "fe8000000000000000fc00fffe000001 04 40 +2 80     eth0",
+            "fe8000000000000000fc00fffe000001 04 40 20 xx     eth0",
+            "fe8000000000000000fc00fffe0000é1 04 40 20 80     eth0",
+        ];
+        for line in bad.iter() {
+            assert!(parse_if_inet6(line).is_none(), "{:?}", line);
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn merge_fills_known_and_adds_extra() {
+        let address = |ip: &str, label: &str, flags: u32| InterfaceAddress {
+            name: label_interface(label).to_string(),
+            label: label.to_string(),
+            address: NetAddress::from(ip.parse::<net::IpAddr>().unwrap()),
+            prefix_length: 64,
+            scope: Some(Ipv6Scope::Global),
+            flags: AddressFlags::from_bits(flags),
+        };
+
+        let mut addresses = vec![address("fd00::2", "eth0", 0)];
+        merge_addresses(
+            &mut addresses,
+            vec![
+                address("fd00::2", "eth0", 0x80),
+                address("fd00::3", "eth0", 0x01),
+                address("fd00::2", "eth1", 0x80),
+            ],
+        );
+
+        assert_eq!(addresses.len(), 3);
+        assert_eq!(addresses[0].flags.bits(), 0x80);
+        assert_eq!(addresses[1].address.to_string(), "fd00::3");
+        assert_eq!(addresses[2].name, "eth1");
+    }
+
+    #[test]
+    fn prefix_length_of_netmask() {
+        let prefix = |mask: &str| prefix_length(mask.parse().unwrap());
+        assert_eq!(prefix("255.255.255.0"), 24);
+        assert_eq!(prefix("255.255.0.0"), 16);
+        assert_eq!(prefix("255.255.255.255"), 32);
+        assert_eq!(prefix("0.0.0.0"), 0);
+        assert_eq!(prefix("ffff:ffff:ffff:ffff::"), 64);
+        assert_eq!(prefix("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff"), 128);
+    }
+
+    #[test]
+    fn interface_of_label() {
+        assert_eq!(label_interface("eth0"), "eth0");
+        assert_eq!(label_interface("eth0:1"), "eth0");
+        assert_eq!(label_interface("lo:backup"), "lo");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn scope_of_ipv6() {
+        let scope = |ip: &str| ipv6_scope(&ip.parse().unwrap());
+        assert_eq!(scope("::1"), Ipv6Scope::Host);
+        assert_eq!(scope("fe80::1"), Ipv6Scope::Link);
+        assert_eq!(scope("fec0::1"), Ipv6Scope::Site);
+        assert_eq!(scope("::10.0.0.1"), Ipv6Scope::Compat);
+        assert_eq!(scope("2001:db8::1"), Ipv6Scope::Global);
+        assert_eq!(scope("fd00::2"), Ipv6Scope::Global);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn if_addrs_ipv4() {
+        for one in if_addrs(libc::AF_INET).unwrap() {
+            assert_eq!(one.address.family, AFFamily::INET);
+            assert!(one.prefix_length <= 32);
+            assert!(one.label.starts_with(&one.name));
+            assert_eq!(one.scope, None);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Provides methods for gathering nfs client and server operation counters
+//!
+
+use super::{result::*, util::*};
+use sigar_sys::*;
+use std::time::Duration;
+
+macro_rules! nfs_ops {
+    ($(#[$attr:meta])* $struct:ident, $raw:ident, $($field:ident), +) => {
+        $(#[$attr])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+        pub struct $struct {
+            $(
+            pub $field: u64,
+            )+
+        }
+
+        impl $struct {
+            fn from_raw(raw: &$raw) -> Self {
+                value_convert!($struct, raw, $($field), +)
+            }
+
+            /// Returns operations done since `prev`, a counter lower than
+            /// in `prev` (nfs restarted) counts from zero
+            pub fn delta(&self, prev: &Self) -> Self {
+                $struct {
+                    $(
+                    $field: counter_delta(prev.$field, self.$field),
+                    )+
+                }
+            }
+
+            /// Returns counters along with operation names
+            pub fn ops(&self) -> Vec<(&'static str, u64)> {
+                vec![$((stringify!($field), self.$field)), +]
+            }
+
+            /// Returns the sum of all counters, saturating at `u64::MAX`
+            pub fn total(&self) -> u64 {
+                0u64 $(.saturating_add(self.$field))+
+            }
+
+            /// Returns operations per second since `prev`, taken `interval` before
+            pub fn rates(&self, prev: &Self, interval: Duration) -> Vec<(&'static str, f64)> {
+                let secs = interval.as_secs() as f64 + f64::from(interval.subsec_nanos()) / 1e9;
+                self.delta(prev)
+                    .ops()
+                    .into_iter()
+                    .map(|(name, delta)| {
+                        let rate = if secs > 0.0 { delta as f64 / secs } else { 0.0 };
+                        (name, rate)
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+// C: sigar_nfs_client_v2_get, sigar_nfs_server_v2_get
+nfs_ops!(
+    /// Nfs v2 operation counters
+    V2,
+    sigar_nfs_v2_t,
+    null,
+    getattr,
+    setattr,
+    root,
+    lookup,
+    readlink,
+    read,
+    writecache,
+    write,
+    create,
+    remove,
+    rename,
+    link,
+    symlink,
+    mkdir,
+    rmdir,
+    readdir,
+    fsstat
+);
+
+// C: sigar_nfs_client_v3_get, sigar_nfs_server_v3_get
+nfs_ops!(
+    /// Nfs v3 operation counters
+    V3,
+    sigar_nfs_v3_t,
+    null,
+    getattr,
+    setattr,
+    lookup,
+    access,
+    readlink,
+    read,
+    write,
+    create,
+    mkdir,
+    symlink,
+    mknod,
+    remove,
+    rmdir,
+    rename,
+    link,
+    readdir,
+    readdirplus,
+    fsstat,
+    fsinfo,
+    pathconf,
+    commit
+);
+
+/// Returns nfs v2 operations made by this host as a client
+pub fn client_v2() -> SigarResult<V2> {
+    Sigar::new()?.nfs_client_v2()
+}
+
+/// Returns nfs v2 operations served by this host
+pub fn server_v2() -> SigarResult<V2> {
+    Sigar::new()?.nfs_server_v2()
+}
+
+/// Returns nfs v3 operations made by this host as a client
+pub fn client_v3() -> SigarResult<V3> {
+    Sigar::new()?.nfs_client_v3()
+}
+
+/// Returns nfs v3 operations served by this host
+pub fn server_v3() -> SigarResult<V3> {
+    Sigar::new()?.nfs_server_v3()
+}
+
+impl Sigar {
+    /// Returns nfs v2 operations made by this host as a client
+    pub fn nfs_client_v2(&self) -> SigarResult<V2> {
+        let raw = ffi_wrap!(self, sigar_nfs_client_v2_get, sigar_nfs_client_v2_t)?;
+        Ok(V2::from_raw(&raw))
+    }
+
+    /// Returns nfs v2 operations served by this host
+    pub fn nfs_server_v2(&self) -> SigarResult<V2> {
+        let raw = ffi_wrap!(self, sigar_nfs_server_v2_get, sigar_nfs_server_v2_t)?;
+        Ok(V2::from_raw(&raw))
+    }
+
+    /// Returns nfs v3 operations made by this host as a client
+    pub fn nfs_client_v3(&self) -> SigarResult<V3> {
+        let raw = ffi_wrap!(self, sigar_nfs_client_v3_get, sigar_nfs_client_v3_t)?;
+        Ok(V3::from_raw(&raw))
+    }
+
+    /// Returns nfs v3 operations served by this host
+    pub fn nfs_server_v3(&self) -> SigarResult<V3> {
+        let raw = ffi_wrap!(self, sigar_nfs_server_v3_get, sigar_nfs_server_v3_t)?;
+        Ok(V3::from_raw(&raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn v2_delta_and_rates() {
+        let prev = V2 {
+            read: 100,
+            write: 50,
+            getattr: 10,
+            ..Default::default()
+        };
+        let cur = V2 {
+            read: 300,
+            write: 70,
+            getattr: 10,
+            ..Default::default()
+        };
+
+        let delta = cur.delta(&prev);
+        assert_eq!(delta.read, 200);
+        assert_eq!(delta.write, 20);
+        assert_eq!(delta.getattr, 0);
+        assert_eq!(delta.total(), 220);
+
+        let rates = cur.rates(&prev, Duration::from_secs(2));
+        assert_eq!(rates.len(), 18);
+        assert!(rates.contains(&("read", 100.0)));
+        assert!(rates.contains(&("write", 10.0)));
+        assert!(rates.contains(&("getattr", 0.0)));
+    }
+
+    #[test]
+    fn total_saturates() {
+        let v2 = V2 {
+            read: u64::MAX,
+            write: 10,
+            ..Default::default()
+        };
+        assert_eq!(v2.total(), u64::MAX);
+        assert_eq!(V2::default().total(), 0);
+
+        let v3 = V3 {
+            read: u64::MAX - 1,
+            commit: u64::MAX,
+            ..Default::default()
+        };
+        assert_eq!(v3.total(), u64::MAX);
+    }
+
+    #[test]
+    fn v3_delta_and_rates() {
+        let prev = V3 {
+            access: 10,
+            commit: 5,
+            ..Default::default()
+        };
+        let cur = V3 {
+            access: 40,
+            commit: 5,
+            ..Default::default()
+        };
+
+        let delta = cur.delta(&prev);
+        assert_eq!(delta.access, 30);
+        assert_eq!(delta.total(), 30);
+
+        let rates = cur.rates(&prev, Duration::from_millis(500));
+        assert_eq!(rates.len(), 22);
+        assert!(rates.contains(&("access", 60.0)));
+        assert!(rates.contains(&("commit", 0.0)));
+    }
+
+    #[test]
+    fn counter_reset_counts_from_zero() {
+        let prev = V3 {
+            read: 1000,
+            write: 10,
+            ..Default::default()
+        };
+        let cur = V3 {
+            read: 40,
+            write: 30,
+            ..Default::default()
+        };
+
+        let delta = cur.delta(&prev);
+        assert_eq!(delta.read, 40);
+        assert_eq!(delta.write, 20);
+
+        let rates = cur.rates(&prev, Duration::from_secs(1));
+        assert!(rates.contains(&("read", 40.0)));
+
+        let v2 = V2 {
+            lookup: 3,
+            ..Default::default()
+        };
+        let prev_v2 = V2 {
+            lookup: 9,
+            ..Default::default()
+        };
+        assert_eq!(v2.delta(&prev_v2).lookup, 3);
+    }
+
+    #[test]
+    fn zero_interval_rates() {
+        let cur = V2 {
+            read: 10,
+            ..Default::default()
+        };
+        let rates = cur.rates(&V2::default(), Duration::from_secs(0));
+        assert!(rates.iter().all(|&(_, rate)| rate == 0.0));
+    }
+}
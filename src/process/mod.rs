@@ -4,22 +4,18 @@
 
 use super::{result::*, util::*};
 use sigar_sys::*;
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Returns pid for current process
 pub fn current_pid() -> SigarResult<u32> {
-    ffi_wrap_sigar_t!((|ptr_t| unsafe { sigar_pid_get(ptr_t) as u32 }))
+    Sigar::new()?.pid()
 }
 
 // C: sigar_proc_kill
 /// Kills a specific process with given process id & signal
 pub fn kill(pid: u32, signal: i32) -> SigarResult<()> {
-    let res = unsafe { sigar_proc_kill(pid as sigar_pid_t, signal as ::std::os::raw::c_int) };
-    if res != SIGAR_CODE_OK {
-        let reason = ffi_wrap_sigar_t!((|ptr_t| error_string(ptr_t, res.into())))?;
-        return Err(Error::from_string(reason));
-    }
-
-    Ok(())
+    Sigar::new()?.proc_kill(pid, signal)
 }
 
 // C: sigar_proc_list_get
@@ -27,12 +23,7 @@ pub type PIDList = Vec<u32>;
 
 /// Returns pid list
 pub fn list() -> SigarResult<PIDList> {
-    ffi_wrap_destroy!(
-        sigar_proc_list_get,
-        sigar_proc_list_destroy,
-        sigar_proc_list_t,
-        (|list: &sigar_proc_list_t| ffi_extract_list!(list, (|one: &sigar_pid_t| *one as u32)))
-    )
+    Sigar::new()?.proc_list()
 }
 
 // C: sigar_proc_stat_get
@@ -50,11 +41,7 @@ pub struct Summary {
 
 /// Returns summary of all processes
 pub fn summary() -> SigarResult<Summary> {
-    let raw = ffi_wrap!(sigar_proc_stat_get, sigar_proc_stat_t)?;
-
-    Ok(value_convert!(
-        Summary, raw, total, sleeping, running, zombie, stopped, idle, threads
-    ))
+    Sigar::new()?.proc_summary()
 }
 
 // C: sigar_proc_mem_get
@@ -71,17 +58,7 @@ pub struct Mem {
 
 /// Returns memory usage for given pid
 pub fn mem(pid: u32) -> SigarResult<Mem> {
-    let raw = ffi_wrap!(sigar_proc_mem_get, (pid as sigar_pid_t), sigar_proc_mem_t)?;
-    Ok(value_convert!(
-        Mem,
-        raw,
-        size,
-        resident,
-        share,
-        minor_faults,
-        major_faults,
-        page_faults,
-    ))
+    Sigar::new()?.proc_mem(pid)
 }
 
 // C: sigar_proc_disk_io_get
@@ -95,38 +72,14 @@ pub struct DiskIO {
 
 /// Returns disk io for given pid
 pub fn disk_io(pid: u32) -> SigarResult<DiskIO> {
-    let raw = ffi_wrap!(
-        sigar_proc_disk_io_get,
-        (pid as sigar_pid_t),
-        sigar_proc_disk_io_t
-    )?;
-
-    Ok(value_convert!(
-        DiskIO,
-        raw,
-        bytes_read,
-        bytes_written,
-        bytes_total,
-    ))
+    Sigar::new()?.proc_disk_io(pid)
 }
 
 // C: sigar_proc_cumulative_disk_io_get
 
 /// Returns cumulative disk io for given pid
 pub fn cum_disk_io(pid: u32) -> SigarResult<DiskIO> {
-    let raw = ffi_wrap!(
-        sigar_proc_cumulative_disk_io_get,
-        (pid as sigar_pid_t),
-        sigar_proc_cumulative_disk_io_t
-    )?;
-
-    Ok(value_convert!(
-        DiskIO,
-        raw,
-        bytes_read,
-        bytes_written,
-        bytes_total,
-    ))
+    Sigar::new()?.proc_cum_disk_io(pid)
 }
 
 // C: sigar_proc_cred_get
@@ -142,9 +95,7 @@ pub struct Cred {
 
 /// Returns creds for given pid
 pub fn cred(pid: u32) -> SigarResult<Cred> {
-    let raw = ffi_wrap!(sigar_proc_cred_get, (pid as sigar_pid_t), sigar_proc_cred_t)?;
-
-    Ok(value_convert!(Cred, raw, uid, gid, euid, egid))
+    Sigar::new()?.proc_cred(pid)
 }
 
 // C: sigar_proc_cred_name_get
@@ -155,17 +106,9 @@ pub struct CredName {
     pub group: Vec<u8>,
 }
 
+/// Returns cred names for given pid
 pub fn cred_name(pid: u32) -> SigarResult<CredName> {
-    let raw = ffi_wrap!(
-        sigar_proc_cred_name_get,
-        (pid as sigar_pid_t),
-        sigar_proc_cred_name_t
-    )?;
-
-    Ok(CredName {
-        user: chars_to_bytes(&raw.user[..]),
-        group: chars_to_bytes(&raw.group[..]),
-    })
+    Sigar::new()?.proc_cred_name(pid)
 }
 
 // C: sigar_proc_time_get
@@ -180,9 +123,7 @@ pub struct Time {
 
 /// Returns process time for given pid
 pub fn time(pid: u32) -> SigarResult<Time> {
-    let raw = ffi_wrap!(sigar_proc_time_get, (pid as sigar_pid_t), sigar_proc_time_t)?;
-
-    Ok(value_convert!(Time, raw, start_time, user, sys, total))
+    Sigar::new()?.proc_time(pid)
 }
 
 // C: sigar_proc_cpu_get
@@ -198,12 +139,151 @@ pub struct CPU {
 }
 
 /// Returns cpu usage for given pid
+///
+/// `percent` is always 0 here since the handle is closed right after the call,
+/// use [`CPUSampler`] to track it across calls.
+///
+/// [`CPUSampler`]: struct.CPUSampler.html
 pub fn cpu(pid: u32) -> SigarResult<CPU> {
-    let raw = ffi_wrap!(sigar_proc_cpu_get, (pid as sigar_pid_t), sigar_proc_cpu_t)?;
+    Sigar::new()?.proc_cpu(pid)
+}
+
+/// How long sigar serves process times of the last read pid from its cache,
+/// SIGAR_LAST_PROC_EXPIRE
+const PROC_CACHE_MILLIS: u64 = 2000;
+
+#[derive(Debug, Clone, Copy)]
+struct CPUSample {
+    start_time: u64,
+    total: u64,
+    last_time: u64,
+    percent: f64,
+}
+
+/// Samples process cpu usage, keeping the previous sample of each process
+/// to calculate `percent` from.
+///
+/// Samples are keyed by pid and start time, so a reused pid starts over
+/// instead of being compared with the exited process.
+/// `percent` is the fraction of one cpu used since the previous sample,
+/// or since the process started for the first sample.
+///
+/// sigar caches process times of the last pid read for 2 seconds on linux,
+/// so sample a pid at most every 2 seconds: an unchanged reading within that
+/// interval keeps the previous sample and its `percent`.
+#[derive(Debug)]
+pub struct CPUSampler {
+    sigar: Sigar,
+    samples: HashMap<u32, CPUSample>,
+}
+
+impl CPUSampler {
+    /// Returns a sampler with no previous samples
+    pub fn new() -> SigarResult<Self> {
+        Ok(CPUSampler {
+            sigar: Sigar::new()?,
+            samples: HashMap::new(),
+        })
+    }
+
+    /// Returns cpu usage for given pid
+    ///
+    /// The previous sample of the pid is dropped if the process is gone.
+    pub fn sample(&mut self, pid: u32) -> SigarResult<CPU> {
+        let time = match self.sigar.proc_time(pid) {
+            Ok(time) => time,
+            Err(e) => {
+                self.samples.remove(&pid);
+                return Err(e);
+            }
+        };
+
+        Ok(self.record(pid, &time, now_millis()))
+    }
+
+    /// Stores `time` of `pid` read at `now` (epoch milliseconds),
+    /// returning usage since the previous sample
+    fn record(&mut self, pid: u32, time: &Time, now: u64) -> CPU {
+        if let Some(prev) = self.samples.get(&pid) {
+            if prev.start_time == time.start_time
+                && prev.total == time.total
+                && now.saturating_sub(prev.last_time) < PROC_CACHE_MILLIS
+            {
+                // likely served from sigar's cache, not a new reading
+                return CPU {
+                    start_time: time.start_time,
+                    user: time.user,
+                    sys: time.sys,
+                    total: time.total,
+                    last_time: prev.last_time,
+                    percent: prev.percent,
+                };
+            }
+        }
+
+        let percent = match self.samples.get(&pid) {
+            Some(prev) if prev.start_time == time.start_time => {
+                if now <= prev.last_time {
+                    prev.percent
+                } else {
+                    time.total.saturating_sub(prev.total) as f64 / (now - prev.last_time) as f64
+                }
+            }
+            _ if now > time.start_time => time.total as f64 / (now - time.start_time) as f64,
+            _ => 0.0,
+        };
+
+        self.samples.insert(
+            pid,
+            CPUSample {
+                start_time: time.start_time,
+                total: time.total,
+                last_time: now,
+                percent,
+            },
+        );
+
+        CPU {
+            start_time: time.start_time,
+            user: time.user,
+            sys: time.sys,
+            total: time.total,
+            last_time: now,
+            percent,
+        }
+    }
+
+    /// Returns cpu usage for all processes
+    ///
+    /// Processes that exit or can't be read during the scan are skipped,
+    /// and samples of processes no longer listed are dropped.
+    pub fn sample_all(&mut self) -> SigarResult<Vec<(u32, CPU)>> {
+        let pids = self.sigar.proc_list()?;
+
+        let mut list = Vec::with_capacity(pids.len());
+        for pid in pids.iter() {
+            if let Ok(cpu) = self.sample(*pid) {
+                list.push((*pid, cpu));
+            }
+        }
+
+        self.retain(&pids);
+
+        Ok(list)
+    }
+
+    /// Drops samples of processes not in given pid list
+    pub fn retain(&mut self, pids: &[u32]) {
+        let alive: HashSet<u32> = pids.iter().cloned().collect();
+        self.samples.retain(|pid, _| alive.contains(pid));
+    }
+}
 
-    Ok(value_convert!(
-        CPU, raw, start_time, user, sys, total, last_time, percent,
-    ))
+fn now_millis() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(d) => d.as_secs() * 1000 + u64::from(d.subsec_millis()),
+        Err(_) => 0,
+    }
 }
 
 // C: sigar_proc_state_get
@@ -221,17 +301,7 @@ pub struct State {
 
 /// Returns process state for given pid
 pub fn state(pid: u32) -> SigarResult<State> {
-    let raw = ffi_wrap!(
-        sigar_proc_state_get,
-        (pid as sigar_pid_t),
-        sigar_proc_state_t
-    )?;
-
-    Ok(value_convert!(
-        State, raw, ppid, tty, priority, nice, processor, threads,
-        (name: chars_to_bytes(&raw.name[..])),
-        (state: raw.state as u8),
-    ))
+    Sigar::new()?.proc_state(pid)
 }
 
 // C: sigar_proc_fd_get
@@ -243,9 +313,294 @@ pub struct FD {
 
 /// Returns fd summary for given pid
 pub fn fd(pid: u32) -> SigarResult<FD> {
-    let raw = ffi_wrap!(sigar_proc_fd_get, (pid as sigar_pid_t), sigar_proc_fd_t)?;
+    Sigar::new()?.proc_fd(pid)
+}
+
+// C: sigar_resource_limit_get
+/// A resource limit value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    Limited(u64),
+    Unlimited,
+}
+
+impl Limit {
+    fn from_raw(raw: u64) -> Self {
+        // RLIM_INFINITY is all ones on linux and windows, i64::MAX on bsd & solaris
+        if raw == u64::MAX || raw == i64::MAX as u64 {
+            return Limit::Unlimited;
+        }
+
+        Limit::Limited(raw)
+    }
+
+    /// Returns true if there is no limit
+    pub fn is_unlimited(&self) -> bool {
+        *self == Limit::Unlimited
+    }
+
+    /// Returns the limit value, `None` if unlimited
+    pub fn value(&self) -> Option<u64> {
+        match *self {
+            Limit::Limited(v) => Some(v),
+            Limit::Unlimited => None,
+        }
+    }
+
+    /// Returns true if `usage` is within the limit
+    pub fn allows(&self, usage: u64) -> bool {
+        match *self {
+            Limit::Limited(v) => usage <= v,
+            Limit::Unlimited => true,
+        }
+    }
+}
+
+/// Soft & hard limit pair
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceLimit {
+    pub cur: Limit,
+    pub max: Limit,
+}
+
+/// Resource limits of current process
+///
+/// `file_size`, `data`, `stack`, `core`, `memory` and `virtual_memory` are in KiB,
+/// `pipe_size` in 512 bytes blocks, `cpu` in seconds.
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceLimits {
+    pub cpu: ResourceLimit,
+    pub file_size: ResourceLimit,
+    pub pipe_size: ResourceLimit,
+    pub data: ResourceLimit,
+    pub stack: ResourceLimit,
+    pub core: ResourceLimit,
+    pub memory: ResourceLimit,
+    pub processes: ResourceLimit,
+    pub open_files: ResourceLimit,
+    pub virtual_memory: ResourceLimit,
+}
+
+macro_rules! resource_limit {
+    ($raw:ident, $cur:ident, $max:ident) => {
+        ResourceLimit {
+            cur: Limit::from_raw($raw.$cur),
+            max: Limit::from_raw($raw.$max),
+        }
+    };
+}
+
+impl ResourceLimits {
+    fn from_raw(raw: &sigar_resource_limit_t) -> Self {
+        ResourceLimits {
+            cpu: resource_limit!(raw, cpu_cur, cpu_max),
+            file_size: resource_limit!(raw, file_size_cur, file_size_max),
+            pipe_size: resource_limit!(raw, pipe_size_cur, pipe_size_max),
+            data: resource_limit!(raw, data_cur, data_max),
+            stack: resource_limit!(raw, stack_cur, stack_max),
+            core: resource_limit!(raw, core_cur, core_max),
+            memory: resource_limit!(raw, memory_cur, memory_max),
+            processes: resource_limit!(raw, processes_cur, processes_max),
+            open_files: resource_limit!(raw, open_files_cur, open_files_max),
+            virtual_memory: resource_limit!(raw, virtual_memory_cur, virtual_memory_max),
+        }
+    }
+}
+
+/// Returns resource limits of current process
+pub fn resource_limits() -> SigarResult<ResourceLimits> {
+    Sigar::new()?.resource_limits()
+}
+
+impl Sigar {
+    /// Returns pid for current process
+    pub fn pid(&self) -> SigarResult<u32> {
+        ffi_wrap_sigar_t!(self, (|ptr_t| unsafe { sigar_pid_get(ptr_t) as u32 }))
+    }
+
+    /// Kills a specific process with given process id & signal
+    pub fn proc_kill(&self, pid: u32, signal: i32) -> SigarResult<()> {
+        let res = unsafe { sigar_proc_kill(pid as sigar_pid_t, signal as ::std::os::raw::c_int) };
+        if res != SIGAR_CODE_OK {
+            return Err(Error::new(self.ptr, res)
+                .with_op("sigar_proc_kill")
+                .with_pid(pid));
+        }
 
-    Ok(value_convert!(FD, raw, total,))
+        Ok(())
+    }
+
+    /// Returns pid list
+    pub fn proc_list(&self) -> SigarResult<PIDList> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_proc_list_get,
+            sigar_proc_list_destroy,
+            sigar_proc_list_t,
+            (|list: &sigar_proc_list_t| ffi_extract_list!(list, (|one: &sigar_pid_t| *one as u32)))
+        )
+    }
+
+    /// Returns summary of all processes
+    pub fn proc_summary(&self) -> SigarResult<Summary> {
+        let raw = ffi_wrap!(self, sigar_proc_stat_get, sigar_proc_stat_t)?;
+
+        Ok(value_convert!(
+            Summary, raw, total, sleeping, running, zombie, stopped, idle, threads
+        ))
+    }
+
+    /// Returns memory usage for given pid
+    pub fn proc_mem(&self, pid: u32) -> SigarResult<Mem> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_mem_get,
+            (pid as sigar_pid_t),
+            sigar_proc_mem_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+        Ok(value_convert!(
+            Mem,
+            raw,
+            size,
+            resident,
+            share,
+            minor_faults,
+            major_faults,
+            page_faults,
+        ))
+    }
+
+    /// Returns disk io for given pid
+    pub fn proc_disk_io(&self, pid: u32) -> SigarResult<DiskIO> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_disk_io_get,
+            (pid as sigar_pid_t),
+            sigar_proc_disk_io_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(
+            DiskIO,
+            raw,
+            bytes_read,
+            bytes_written,
+            bytes_total,
+        ))
+    }
+
+    /// Returns cumulative disk io for given pid
+    pub fn proc_cum_disk_io(&self, pid: u32) -> SigarResult<DiskIO> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_cumulative_disk_io_get,
+            (pid as sigar_pid_t),
+            sigar_proc_cumulative_disk_io_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(
+            DiskIO,
+            raw,
+            bytes_read,
+            bytes_written,
+            bytes_total,
+        ))
+    }
+
+    /// Returns creds for given pid
+    pub fn proc_cred(&self, pid: u32) -> SigarResult<Cred> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_cred_get,
+            (pid as sigar_pid_t),
+            sigar_proc_cred_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(Cred, raw, uid, gid, euid, egid))
+    }
+
+    /// Returns cred names for given pid
+    pub fn proc_cred_name(&self, pid: u32) -> SigarResult<CredName> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_cred_name_get,
+            (pid as sigar_pid_t),
+            sigar_proc_cred_name_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(CredName {
+            user: chars_to_bytes(&raw.user[..]),
+            group: chars_to_bytes(&raw.group[..]),
+        })
+    }
+
+    /// Returns process time for given pid
+    pub fn proc_time(&self, pid: u32) -> SigarResult<Time> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_time_get,
+            (pid as sigar_pid_t),
+            sigar_proc_time_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(Time, raw, start_time, user, sys, total))
+    }
+
+    /// Returns cpu usage for given pid
+    pub fn proc_cpu(&self, pid: u32) -> SigarResult<CPU> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_cpu_get,
+            (pid as sigar_pid_t),
+            sigar_proc_cpu_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(
+            CPU, raw, start_time, user, sys, total, last_time, percent,
+        ))
+    }
+
+    /// Returns process state for given pid
+    pub fn proc_state(&self, pid: u32) -> SigarResult<State> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_state_get,
+            (pid as sigar_pid_t),
+            sigar_proc_state_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(
+            State, raw, ppid, tty, priority, nice, processor, threads,
+            (name: chars_to_bytes(&raw.name[..])),
+            (state: raw.state as u8),
+        ))
+    }
+
+    /// Returns fd summary for given pid
+    pub fn proc_fd(&self, pid: u32) -> SigarResult<FD> {
+        let raw = ffi_wrap!(
+            self,
+            sigar_proc_fd_get,
+            (pid as sigar_pid_t),
+            sigar_proc_fd_t
+        )
+        .map_err(|e| e.with_pid(pid))?;
+
+        Ok(value_convert!(FD, raw, total,))
+    }
+
+    /// Returns resource limits of current process
+    pub fn resource_limits(&self) -> SigarResult<ResourceLimits> {
+        let raw = ffi_wrap!(self, sigar_resource_limit_get, sigar_resource_limit_t)?;
+        Ok(ResourceLimits::from_raw(&raw))
+    }
 }
 
 // TODO: some methods
@@ -254,3 +609,139 @@ pub fn fd(pid: u32) -> SigarResult<FD> {
 // C: sigar_proc_exe_get
 // C: sigar_proc_modules_get
 // C: sigar_proc_port_get
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_sentinels_are_unlimited() {
+        assert_eq!(Limit::from_raw(u64::MAX), Limit::Unlimited);
+        assert_eq!(Limit::from_raw(i64::MAX as u64), Limit::Unlimited);
+        assert!(Limit::Unlimited.allows(u64::MAX));
+        assert_eq!(Limit::Unlimited.value(), None);
+    }
+
+    #[test]
+    fn limit_value() {
+        let limit = Limit::from_raw(1024);
+        assert_eq!(limit, Limit::Limited(1024));
+        assert!(!limit.is_unlimited());
+        assert_eq!(limit.value(), Some(1024));
+        assert!(limit.allows(1024));
+        assert!(!limit.allows(1025));
+        assert_eq!(Limit::from_raw(0), Limit::Limited(0));
+    }
+
+    #[test]
+    fn resource_limits_from_raw() {
+        let raw = sigar_resource_limit_t {
+            open_files_cur: 1024,
+            open_files_max: u64::MAX,
+            ..Default::default()
+        };
+        let limits = ResourceLimits::from_raw(&raw);
+        assert_eq!(limits.open_files.cur, Limit::Limited(1024));
+        assert_eq!(limits.open_files.max, Limit::Unlimited);
+    }
+
+    fn time(start_time: u64, total: u64) -> Time {
+        Time {
+            start_time,
+            user: total,
+            sys: 0,
+            total,
+        }
+    }
+
+    #[test]
+    fn first_sample_averages_since_start() {
+        let mut sampler = CPUSampler::new().unwrap();
+        let cpu = sampler.record(1, &time(1000, 500), 2000);
+        assert_eq!(cpu.percent, 0.5);
+        assert_eq!(cpu.last_time, 2000);
+    }
+
+    #[test]
+    fn first_sample_at_start_is_zero() {
+        let mut sampler = CPUSampler::new().unwrap();
+        assert_eq!(sampler.record(1, &time(1000, 0), 1000).percent, 0.0);
+    }
+
+    #[test]
+    fn next_sample_uses_delta() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 500), 2000);
+        let cpu = sampler.record(1, &time(1000, 1250), 3000);
+        assert_eq!(cpu.percent, 0.75);
+    }
+
+    #[test]
+    fn cached_reading_keeps_previous_sample() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 500), 2000);
+
+        // same total within the cache interval
+        let cpu = sampler.record(1, &time(1000, 500), 3000);
+        assert_eq!(cpu.percent, 0.5);
+        assert_eq!(cpu.last_time, 2000);
+
+        // next real reading is compared with the kept sample
+        let cpu = sampler.record(1, &time(1000, 1500), 4000);
+        assert_eq!(cpu.percent, 0.5);
+        assert_eq!(cpu.last_time, 4000);
+    }
+
+    #[test]
+    fn idle_process_after_cache_interval() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 500), 2000);
+        assert_eq!(sampler.record(1, &time(1000, 500), 4000).percent, 0.0);
+    }
+
+    #[test]
+    fn same_timestamp_keeps_previous_percent() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 500), 2000);
+        assert_eq!(sampler.record(1, &time(1000, 900), 2000).percent, 0.5);
+    }
+
+    #[test]
+    fn reused_pid_starts_over() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 900), 2000);
+        // new process with the same pid, lower total than the previous one
+        let cpu = sampler.record(1, &time(2500, 100), 3000);
+        assert_eq!(cpu.percent, 0.2);
+    }
+
+    #[test]
+    fn lower_total_does_not_underflow() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 900), 2000);
+        assert_eq!(sampler.record(1, &time(1000, 800), 3000).percent, 0.0);
+    }
+
+    #[test]
+    fn retain_drops_exited_pids() {
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(1, &time(1000, 500), 2000);
+        sampler.record(2, &time(1000, 500), 2000);
+        sampler.retain(&[2]);
+        assert!(!sampler.samples.contains_key(&1));
+        assert!(sampler.samples.contains_key(&2));
+
+        // sampled again as a first sample
+        let cpu = sampler.record(1, &time(1000, 1500), 3000);
+        assert_eq!(cpu.percent, 0.75);
+    }
+
+    #[test]
+    fn gone_pid_drops_sample() {
+        let pid = 0x7fff_fff0;
+        let mut sampler = CPUSampler::new().unwrap();
+        sampler.record(pid, &time(1000, 500), 2000);
+        assert!(sampler.sample(pid).unwrap_err().is_not_found());
+        assert!(!sampler.samples.contains_key(&pid));
+    }
+}
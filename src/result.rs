@@ -1,82 +1,270 @@
 use sigar_sys;
-use std::{error::Error as stdError, fmt};
+use std::{error::Error as stdError, ffi::NulError, fmt, io, time::Duration};
+
+#[cfg(unix)]
+use std::ffi::CStr;
 
 pub(crate) use sigar_sys::SIGAR_CODE_OK;
 
 /// Type alias for [`Result<T, Error>`]
 pub type SigarResult<T = ()> = Result<T, Error>;
 
-/// Wraps inner reason from sigar::sigar_strerror
-#[derive(Debug)]
-pub enum Error {
+/// Kinds of errors, decoded from sigar or os error codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
     NotImplementd,
     ENOENT,
     EACCES,
     ENXIO,
-    CString(String),
-    Others(String),
+    ESRCH,
+    CString,
+    /// the call did not complete before its deadline
+    Timeout,
+    Others,
+}
+
+/// The pid or name a failed call was made for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Pid(u32),
+    Name(String),
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            Target::Pid(pid) => write!(f, "pid {}", pid),
+            Target::Name(ref name) => f.write_str(name),
+        }
+    }
+}
+
+/// Wraps inner reason from sigar::sigar_strerror,
+/// along with the raw code and the call that failed
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    code: Option<i32>,
+    op: Option<&'static str>,
+    target: Option<Target>,
+    reason: String,
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        f.write_str(self.description())
+        if let Some(op) = self.op {
+            match self.target {
+                Some(ref target) => write!(f, "{}({}): ", op, target)?,
+                None => write!(f, "{}: ", op)?,
+            }
+        }
+
+        f.write_str(&self.reason)?;
+
+        if let Some(code) = self.code {
+            write!(f, " (code {})", code)?;
+        }
+
+        Ok(())
     }
 }
 
 impl stdError for Error {}
 
+impl From<NulError> for Error {
+    fn from(e: NulError) -> Self {
+        Error::with_kind(ErrorKind::CString, e.to_string())
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        let code = e.raw_os_error();
+        let kind = code.and_then(match_code).unwrap_or(ErrorKind::Others);
+        // the code is appended on display
+        let reason = match code {
+            Some(code) => e
+                .to_string()
+                .trim_end_matches(&format!(" (os error {})", code))
+                .to_string(),
+            None => e.to_string(),
+        };
+
+        Error {
+            code,
+            ..Error::with_kind(kind, reason)
+        }
+    }
+}
+
 impl Error {
-    fn description(&self) -> &str {
-        match self {
-            Error::NotImplementd => "not implemented on current os",
-            Error::ENOENT => "no such file or directory",
-            Error::EACCES => "permission denied",
-            Error::ENXIO => "no such device or address",
-            Error::CString(ref reason) => reason,
-            Error::Others(ref reason) => reason,
+    fn with_kind(kind: ErrorKind, reason: String) -> Self {
+        Error {
+            kind,
+            code: None,
+            op: None,
+            target: None,
+            reason,
         }
     }
 
     pub(crate) fn new(t: *mut sigar_sys::sigar_t, code: i32) -> Self {
-        if let Some(e) = match_code(code) {
-            return e;
+        let kind = match_code(code).unwrap_or(ErrorKind::Others);
+        let reason = match kind {
+            ErrorKind::NotImplementd => "not implemented on current os".to_string(),
+            _ => sigar_sys::error_string(t, code),
+        };
+
+        Error {
+            code: Some(code),
+            ..Error::with_kind(kind, reason)
         }
+    }
 
-        Error::Others(sigar_sys::error_string(t, code.into()))
+    /// Error of a call which did not complete within `timeout`
+    pub(crate) fn timeout(timeout: Duration) -> Self {
+        Error::with_kind(ErrorKind::Timeout, format!("timed out after {:?}", timeout))
     }
 
-    pub(crate) fn from_str(s: &str) -> Self {
-        Error::Others(s.to_string())
+    /// Error of a call not made since a previous call on `target` is still blocked
+    pub(crate) fn pending(target: &str) -> Self {
+        Error::with_kind(
+            ErrorKind::Timeout,
+            format!("previous call on {} still pending", target),
+        )
     }
 
-    pub(crate) fn from_string(s: String) -> Self {
-        Error::Others(s)
+    /// Error from an rpc call, e.g. sigar_rpc_ping
+    #[cfg(unix)]
+    pub(crate) fn rpc(code: i32) -> Self {
+        let reason = unsafe {
+            CStr::from_ptr(sigar_sys::sigar_rpc_strerror(code))
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        Error {
+            code: Some(code),
+            ..Error::with_kind(ErrorKind::Others, reason)
+        }
+    }
+
+    /// Sets the name of the failed sigar call, unless already known
+    pub(crate) fn with_op(mut self, op: &'static str) -> Self {
+        if self.op.is_none() {
+            self.op = Some(op);
+        }
+        self
+    }
+
+    /// Sets the pid the failed call was made for
+    pub(crate) fn with_pid(mut self, pid: u32) -> Self {
+        self.target = Some(Target::Pid(pid));
+        self
+    }
+
+    /// Sets the name (interface, path ...) the failed call was made for
+    pub(crate) fn with_name(mut self, name: &str) -> Self {
+        self.target = Some(Target::Name(name.to_string()));
+        self
+    }
+
+    /// Returns the kind of error
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Returns the raw sigar or os error code
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+
+    /// Returns the name of the failed sigar call, e.g. `sigar_proc_mem_get`
+    pub fn op(&self) -> Option<&'static str> {
+        self.op
+    }
+
+    /// Returns the pid or name the failed call was made for
+    pub fn target(&self) -> Option<&Target> {
+        self.target.as_ref()
     }
 
     /// Returns the error reason
     pub fn reason(&self) -> String {
-        self.description().to_string()
+        self.reason.clone()
+    }
+
+    /// Returns true if the call is not implemented on current os
+    pub fn is_not_implemented(&self) -> bool {
+        self.kind == ErrorKind::NotImplementd
+    }
+
+    /// Returns true if the file, process or device was not found
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self.kind,
+            ErrorKind::ENOENT | ErrorKind::ESRCH | ErrorKind::ENXIO
+        )
+    }
+
+    /// Returns true if permission was denied
+    pub fn is_permission_denied(&self) -> bool {
+        self.kind == ErrorKind::EACCES
+    }
+
+    /// Returns true if the call did not complete before its deadline
+    pub fn is_timeout(&self) -> bool {
+        self.kind == ErrorKind::Timeout
+    }
+
+    /// Returns true if the call was made for a process which no longer exists
+    pub fn is_process_gone(&self) -> bool {
+        match self.target {
+            Some(Target::Pid(_)) => matches!(self.kind, ErrorKind::ENOENT | ErrorKind::ESRCH),
+            _ => false,
+        }
     }
 }
 
 #[cfg(windows)]
-fn match_code(code: i32) -> Option<Error> {
+fn match_code(code: i32) -> Option<ErrorKind> {
     match code as u32 {
-        sigar_sys::SIGAR_ENOTIMPL => Some(Error::NotImplementd),
-        0x2 => Some(Error::ENOENT),
-        0x5 => Some(Error::EACCES),
-        0x77 => Some(Error::ENXIO),
+        sigar_sys::SIGAR_ENOTIMPL => Some(ErrorKind::NotImplementd),
+        0x2 => Some(ErrorKind::ENOENT),
+        0x5 => Some(ErrorKind::EACCES),
+        0x77 => Some(ErrorKind::ENXIO),
         _ => None,
     }
 }
 
 #[cfg(unix)]
-fn match_code(code: i32) -> Option<Error> {
+fn match_code(code: i32) -> Option<ErrorKind> {
     match code as u32 {
-        sigar_sys::SIGAR_ENOTIMPL => Some(Error::NotImplementd),
-        2 => Some(Error::ENOENT),
-        13 => Some(Error::EACCES),
-        6 => Some(Error::ENXIO),
+        sigar_sys::SIGAR_ENOTIMPL => Some(ErrorKind::NotImplementd),
+        2 => Some(ErrorKind::ENOENT),
+        3 => Some(ErrorKind::ESRCH),
+        13 => Some(ErrorKind::EACCES),
+        6 => Some(ErrorKind::ENXIO),
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn io_error_keeps_code() {
+        let e = Error::from(io::Error::from_raw_os_error(2));
+        assert_eq!(e.code(), Some(2));
+        assert_eq!(e.kind(), ErrorKind::ENOENT);
+        assert!(e.is_not_found());
+        assert_eq!(e.to_string(), format!("{} (code 2)", e.reason()));
+        assert!(!e.reason().contains("os error"));
+
+        let e = Error::from(io::Error::other("boom"));
+        assert_eq!(e.code(), None);
+        assert_eq!(e.kind(), ErrorKind::Others);
+        assert_eq!(e.to_string(), "boom");
+    }
+}
@@ -0,0 +1,54 @@
+//! Provides methods for gathering system informations
+//!
+
+use super::{result::*, util::*};
+use sigar_sys::*;
+
+// C: sigar_sys_info_get
+/// System information
+///
+/// On linux, `vendor*` fields describe the distribution,
+/// detected from the release files under `/etc`.
+#[derive(Debug, Clone)]
+pub struct Info {
+    pub name: String,
+    pub version: String,
+    pub arch: String,
+    pub machine: String,
+    pub description: String,
+    pub patch_level: String,
+    pub vendor: String,
+    pub vendor_version: String,
+    pub vendor_name: String,
+    pub vendor_code_name: String,
+}
+
+impl Info {
+    fn from_raw(raw: &sigar_sys_info_t) -> Self {
+        Info {
+            name: chars_to_string(&raw.name[..]),
+            version: chars_to_string(&raw.version[..]),
+            arch: chars_to_string(&raw.arch[..]),
+            machine: chars_to_string(&raw.machine[..]),
+            description: chars_to_string(&raw.description[..]),
+            patch_level: chars_to_string(&raw.patch_level[..]),
+            vendor: chars_to_string(&raw.vendor[..]),
+            vendor_version: chars_to_string(&raw.vendor_version[..]),
+            vendor_name: chars_to_string(&raw.vendor_name[..]),
+            vendor_code_name: chars_to_string(&raw.vendor_code_name[..]),
+        }
+    }
+}
+
+/// Returns system information
+pub fn info() -> SigarResult<Info> {
+    Sigar::new()?.sys_info()
+}
+
+impl Sigar {
+    /// Returns system information
+    pub fn sys_info(&self) -> SigarResult<Info> {
+        let raw = ffi_wrap!(self, sigar_sys_info_get, sigar_sys_info_t)?;
+        Ok(Info::from_raw(&raw))
+    }
+}
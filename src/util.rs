@@ -1,7 +1,7 @@
-use super::result::{Error, SigarResult};
-use sigar_sys::{sigar_close, sigar_open, sigar_t, SIGAR_CODE_OK};
-use std::{self, os::raw::c_char};
+use std::ffi::CStr;
+use std::os::raw::c_char;
 
+pub(crate) use super::handle::Sigar;
 #[allow(unused_imports)]
 pub(crate) use super::integer::CastTo;
 pub(crate) use std::slice::from_raw_parts;
@@ -16,27 +16,37 @@ pub(crate) fn chars_to_bytes(chars: &[c_char]) -> Vec<u8> {
     bytes
 }
 
-pub(crate) struct SigarPtr {
-    pub(crate) ptr: *mut sigar_t,
+pub(crate) fn chars_to_string(chars: &[c_char]) -> String {
+    String::from_utf8_lossy(strip_bytes(&chars_to_bytes(chars))).into_owned()
 }
 
-impl SigarPtr {
-    pub(crate) fn new() -> SigarResult<Self> {
-        let mut ptr: *mut sigar_t = std::ptr::null_mut();
-
-        let res = unsafe { sigar_open(&mut ptr) };
-        if res != SIGAR_CODE_OK {
-            return Err(Error::new(ptr, res));
-        }
+/// Copies `s` into `chars`, truncated to leave room for the ending zero
+pub(crate) fn string_to_chars(s: &str, chars: &mut [c_char]) {
+    let len = s.len().min(chars.len().saturating_sub(1));
+    for (dst, src) in chars.iter_mut().zip(s.as_bytes()[..len].iter()) {
+        *dst = *src as c_char;
+    }
+    if let Some(end) = chars.get_mut(len) {
+        *end = 0;
+    }
+}
 
-        Ok(SigarPtr { ptr })
+/// Returns the increase of a counter since `prev`,
+/// a counter lower than `prev` (reset or wrapped) counts from zero
+pub(crate) fn counter_delta(prev: u64, cur: u64) -> u64 {
+    if cur >= prev {
+        cur - prev
+    } else {
+        cur
     }
 }
 
-impl Drop for SigarPtr {
-    fn drop(&mut self) {
-        unsafe { sigar_close(self.ptr) };
+pub(crate) fn ptr_to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        return String::new();
     }
+
+    unsafe { CStr::from_ptr(ptr).to_string_lossy().into_owned() }
 }
 
 /// Strips ending zeros in the bytes
@@ -53,11 +63,3 @@ pub fn strip_bytes(bytes: &[u8]) -> &[u8] {
 
     &bytes[..len]
 }
-
-pub(crate) fn u32_reverse(src: u32) -> u32 {
-    const MASK_U8: u32 = !(0u32) >> 24;
-    (src & MASK_U8) << 24
-        | (src >> 8 & MASK_U8) << 16
-        | (src >> 16 & MASK_U8) << 8
-        | (src >> 24 & MASK_U8)
-}
@@ -0,0 +1,40 @@
+use super::util::*;
+use sigar_sys::*;
+
+/// Version and build information of the native sigar library
+#[derive(Debug, Clone)]
+pub struct Version {
+    pub build_date: String,
+    pub scm_revision: String,
+    pub version: String,
+    pub archname: String,
+    pub archlib: String,
+    pub binname: String,
+    pub description: String,
+    pub major: i32,
+    pub minor: i32,
+    pub maint: i32,
+    pub build: i32,
+}
+
+// C: sigar_version_get
+/// Returns version of the native sigar library
+pub fn version() -> Version {
+    let raw = unsafe { &*sigar_version_get() };
+
+    value_convert!(
+        Version,
+        raw,
+        major,
+        minor,
+        maint,
+        build,
+        (build_date: ptr_to_string(raw.build_date)),
+        (scm_revision: ptr_to_string(raw.scm_revision)),
+        (version: ptr_to_string(raw.version)),
+        (archname: ptr_to_string(raw.archname)),
+        (archlib: ptr_to_string(raw.archlib)),
+        (binname: ptr_to_string(raw.binname)),
+        (description: ptr_to_string(raw.description)),
+    )
+}
@@ -0,0 +1,47 @@
+//! Provides methods for listing logged-in users
+//!
+
+use super::{result::*, util::*};
+use sigar_sys::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// C: sigar_who_list_get
+/// A logged-in user session
+#[derive(Debug, Clone)]
+pub struct Who {
+    pub user: String,
+    /// tty of the session
+    pub device: String,
+    /// remote host, empty for local sessions
+    pub host: String,
+    pub time: SystemTime,
+}
+
+impl Who {
+    fn from_raw(raw: &sigar_who_t) -> Self {
+        Who {
+            user: chars_to_string(&raw.user[..]),
+            device: chars_to_string(&raw.device[..]),
+            host: chars_to_string(&raw.host[..]),
+            time: UNIX_EPOCH + Duration::from_secs(raw.time),
+        }
+    }
+}
+
+/// Returns logged-in users
+pub fn list() -> SigarResult<Vec<Who>> {
+    Sigar::new()?.who_list()
+}
+
+impl Sigar {
+    /// Returns logged-in users
+    pub fn who_list(&self) -> SigarResult<Vec<Who>> {
+        ffi_wrap_destroy!(
+            self,
+            sigar_who_list_get,
+            sigar_who_list_destroy,
+            sigar_who_list_t,
+            (|list: &sigar_who_list_t| ffi_extract_list!(list, Who::from_raw))
+        )
+    }
+}